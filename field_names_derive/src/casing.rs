@@ -0,0 +1,455 @@
+use darling::{Error, FromMeta};
+use syn::NestedMeta;
+
+/// A casing transform for `rename_all`.
+///
+/// The conversions mirror serde's `rename_all` exactly, so names emitted by this crate match
+/// serde's wire names for the same rule. With the `heck` feature, a `rename_all` can opt into
+/// `heck`'s word splitting instead, which differs from serde on acronyms such as `HTTPStatus`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
+pub(crate) enum RenameRule {
+    LowerCase,
+    UpperCase,
+    PascalCase,
+    CamelCase,
+    SnakeCase,
+    ScreamingSnakeCase,
+    KebabCase,
+    ScreamingKebabCase,
+}
+
+use self::RenameRule::*;
+
+static RENAME_RULES: &[(&str, RenameRule)] = &[
+    ("lowercase", LowerCase),
+    ("UPPERCASE", UpperCase),
+    ("PascalCase", PascalCase),
+    ("camelCase", CamelCase),
+    ("snake_case", SnakeCase),
+    ("SCREAMING_SNAKE_CASE", ScreamingSnakeCase),
+    ("kebab-case", KebabCase),
+    ("SCREAMING-KEBAB-CASE", ScreamingKebabCase),
+];
+
+/// `name` with its first character lowercased, if it's ASCII. Names can be empty, as for a
+/// field named `__`, or start with a multi-byte character, so this can't slice off one byte.
+fn lowercase_first(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+impl RenameRule {
+    /// Apply the serde-compatible conversion to a variant name, whatever features are enabled.
+    /// Generated identifiers use this, so enabling `heck` elsewhere in a build can't rename them.
+    pub fn apply_to_variant_builtin(self, variant: &str) -> String {
+        match self {
+            PascalCase => variant.to_owned(),
+            LowerCase => variant.to_ascii_lowercase(),
+            UpperCase => variant.to_ascii_uppercase(),
+            CamelCase => lowercase_first(variant),
+            SnakeCase => {
+                let mut snake = String::new();
+                for (i, ch) in variant.char_indices() {
+                    if i > 0 && ch.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(ch.to_ascii_lowercase());
+                }
+                snake
+            }
+            ScreamingSnakeCase => SnakeCase
+                .apply_to_variant_builtin(variant)
+                .to_ascii_uppercase(),
+            KebabCase => SnakeCase
+                .apply_to_variant_builtin(variant)
+                .replace('_', "-"),
+            ScreamingKebabCase => ScreamingSnakeCase
+                .apply_to_variant_builtin(variant)
+                .replace('_', "-"),
+        }
+    }
+
+    /// Apply the serde-compatible conversion to a field name, whatever features are enabled.
+    pub fn apply_to_field_builtin(self, field: &str) -> String {
+        match self {
+            LowerCase | SnakeCase => field.to_owned(),
+            UpperCase | ScreamingSnakeCase => field.to_ascii_uppercase(),
+            PascalCase => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for ch in field.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(ch);
+                    }
+                }
+                pascal
+            }
+            CamelCase => lowercase_first(&PascalCase.apply_to_field_builtin(field)),
+            KebabCase => field.replace('_', "-"),
+            ScreamingKebabCase => ScreamingSnakeCase
+                .apply_to_field_builtin(field)
+                .replace('_', "-"),
+        }
+    }
+
+    /// Apply the rule using `heck`, which splits words the same way for fields and variants.
+    /// `lowercase` and `UPPERCASE` don't split words at all, so they are left to the built-ins.
+    #[cfg(feature = "heck")]
+    fn apply_heck(self, name: &str) -> Option<String> {
+        use heck::{
+            ToKebabCase, ToLowerCamelCase, ToShoutyKebabCase, ToShoutySnakeCase, ToSnakeCase,
+            ToUpperCamelCase,
+        };
+
+        match self {
+            LowerCase | UpperCase => None,
+            PascalCase => Some(name.to_upper_camel_case()),
+            CamelCase => Some(name.to_lower_camel_case()),
+            SnakeCase => Some(name.to_snake_case()),
+            ScreamingSnakeCase => Some(name.to_shouty_snake_case()),
+            KebabCase => Some(name.to_kebab_case()),
+            ScreamingKebabCase => Some(name.to_shouty_kebab_case()),
+        }
+    }
+}
+
+impl FromMeta for RenameRule {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        RENAME_RULES
+            .iter()
+            .find(|(name, _)| *name == value)
+            .map(|(_, rule)| *rule)
+            .ok_or_else(|| {
+                let expected = RENAME_RULES
+                    .iter()
+                    .map(|(name, _)| format!("`{}`", name))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Error::custom(format!(
+                    "unknown rename rule `{}`, expected one of {}",
+                    value, expected
+                ))
+            })
+    }
+}
+
+/// A `rename_all` rule for fields or variants, remembering whether `heck` splits the words.
+///
+/// The rule is written either as `rename_all = "snake_case"` or, to choose the word splitting,
+/// as `rename_all(rule = "snake_case", words = "heck")`. Only the second form uses `heck`, so
+/// enabling the feature doesn't change any names by itself, and rules read from serde's
+/// attributes always match serde's wire names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RenameAll {
+    rule: RenameRule,
+    #[cfg_attr(not(feature = "heck"), allow(dead_code))]
+    heck: bool,
+}
+
+impl RenameAll {
+    /// A rule that converts names as serde does.
+    pub fn new(rule: RenameRule) -> Self {
+        RenameAll { rule, heck: false }
+    }
+
+    /// `rule`, splitting words the same way as this rule.
+    pub fn with_rule(self, rule: RenameRule) -> Self {
+        RenameAll { rule, ..self }
+    }
+
+    /// Apply the rule to a `PascalCase` Rust variant name.
+    pub fn apply_to_variant(self, variant: &str) -> String {
+        #[cfg(feature = "heck")]
+        if self.heck {
+            if let Some(renamed) = self.rule.apply_heck(variant) {
+                return renamed;
+            }
+        }
+
+        self.rule.apply_to_variant_builtin(variant)
+    }
+
+    /// Apply the rule to a `snake_case` Rust field name.
+    pub fn apply_to_field(self, field: &str) -> String {
+        #[cfg(feature = "heck")]
+        if self.heck {
+            if let Some(renamed) = self.rule.apply_heck(field) {
+                return renamed;
+            }
+        }
+
+        self.rule.apply_to_field_builtin(field)
+    }
+}
+
+impl FromMeta for RenameAll {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        RenameRule::from_string(value).map(RenameAll::new)
+    }
+
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        #[derive(FromMeta)]
+        struct Options {
+            rule: RenameRule,
+            #[darling(default)]
+            words: Option<Words>,
+        }
+
+        let options = Options::from_list(items)?;
+        Ok(RenameAll {
+            rule: options.rule,
+            heck: options.words == Some(Words::Heck),
+        })
+    }
+}
+
+/// How `rename_all(rule = "...", words = "...")` splits names into words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Words {
+    /// serde's conversions, which treat each capital as the start of a word.
+    Serde,
+    /// `heck`'s, which keep runs of capitals such as `HTTP` together.
+    Heck,
+}
+
+impl FromMeta for Words {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        match value {
+            "serde" => Ok(Words::Serde),
+            "heck" if cfg!(feature = "heck") => Ok(Words::Heck),
+            "heck" => Err(Error::custom(
+                "`words = \"heck\"` requires the `heck` feature of `field_names`",
+            )),
+            _ => Err(Error::unknown_value(value)),
+        }
+    }
+}
+
+/// Turns an identifier into a label of capitalized words, e.g. `minutes_to_midnight` or
+/// `minutesToMidnight` into `Minutes To Midnight`. Words end at `_`, `-` and spaces, before an
+/// uppercase letter that follows a lowercase letter or digit, and before the last letter of a
+/// run of capitals that's followed by a lowercase letter, so `HTTPServer` is `HTTP Server`.
+pub(crate) fn titleize(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' || c == '-' || c == ' ' {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+
+        if c.is_uppercase() && !word.is_empty() {
+            let prev = chars[i - 1];
+            let next_is_lower = matches!(chars.get(i + 1), Some(next) if next.is_lowercase());
+            if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_is_lower) {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+        word.push(c);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use darling::FromMeta;
+    use syn::parse_quote;
+
+    use super::{titleize, RenameAll, RenameRule::*};
+
+    #[test]
+    fn titleize_words() {
+        for &(original, label) in &[
+            ("hello", "Hello"),
+            ("minutes_to_midnight", "Minutes To Midnight"),
+            ("minutesToMidnight", "Minutes To Midnight"),
+            ("SendRequest", "Send Request"),
+            ("HTTPServer", "HTTP Server"),
+            ("http_status_code", "Http Status Code"),
+            ("kebab-case-name", "Kebab Case Name"),
+            ("__leading", "Leading"),
+            ("Z42", "Z42"),
+            ("ipv4Address", "Ipv4 Address"),
+        ] {
+            assert_eq!(titleize(original), label);
+        }
+    }
+
+    #[test]
+    fn rename_variants() {
+        for &(original, lower, upper, camel, snake, screaming, kebab, screaming_kebab) in &[
+            (
+                "Outcome", "outcome", "OUTCOME", "outcome", "outcome", "OUTCOME", "outcome",
+                "OUTCOME",
+            ),
+            (
+                "VeryTasty",
+                "verytasty",
+                "VERYTASTY",
+                "veryTasty",
+                "very_tasty",
+                "VERY_TASTY",
+                "very-tasty",
+                "VERY-TASTY",
+            ),
+            ("A", "a", "A", "a", "a", "A", "a", "A"),
+            ("Z42", "z42", "Z42", "z42", "z42", "Z42", "z42", "Z42"),
+        ] {
+            assert_eq!(LowerCase.apply_to_variant_builtin(original), lower);
+            assert_eq!(UpperCase.apply_to_variant_builtin(original), upper);
+            assert_eq!(PascalCase.apply_to_variant_builtin(original), original);
+            assert_eq!(CamelCase.apply_to_variant_builtin(original), camel);
+            assert_eq!(SnakeCase.apply_to_variant_builtin(original), snake);
+            assert_eq!(
+                ScreamingSnakeCase.apply_to_variant_builtin(original),
+                screaming
+            );
+            assert_eq!(KebabCase.apply_to_variant_builtin(original), kebab);
+            assert_eq!(
+                ScreamingKebabCase.apply_to_variant_builtin(original),
+                screaming_kebab
+            );
+        }
+    }
+
+    #[test]
+    fn rename_fields() {
+        for &(original, upper, pascal, camel, screaming, kebab, screaming_kebab) in &[
+            (
+                "outcome", "OUTCOME", "Outcome", "outcome", "OUTCOME", "outcome", "OUTCOME",
+            ),
+            (
+                "very_tasty",
+                "VERY_TASTY",
+                "VeryTasty",
+                "veryTasty",
+                "VERY_TASTY",
+                "very-tasty",
+                "VERY-TASTY",
+            ),
+            ("a", "A", "A", "a", "A", "a", "A"),
+            ("z42", "Z42", "Z42", "z42", "Z42", "z42", "Z42"),
+        ] {
+            assert_eq!(LowerCase.apply_to_field_builtin(original), original);
+            assert_eq!(UpperCase.apply_to_field_builtin(original), upper);
+            assert_eq!(PascalCase.apply_to_field_builtin(original), pascal);
+            assert_eq!(CamelCase.apply_to_field_builtin(original), camel);
+            assert_eq!(SnakeCase.apply_to_field_builtin(original), original);
+            assert_eq!(
+                ScreamingSnakeCase.apply_to_field_builtin(original),
+                screaming
+            );
+            assert_eq!(KebabCase.apply_to_field_builtin(original), kebab);
+            assert_eq!(
+                ScreamingKebabCase.apply_to_field_builtin(original),
+                screaming_kebab
+            );
+        }
+    }
+
+    #[test]
+    fn camel_case_first_char() {
+        assert_eq!(CamelCase.apply_to_field_builtin("__"), "");
+        assert_eq!(CamelCase.apply_to_variant_builtin(""), "");
+        assert_eq!(CamelCase.apply_to_field_builtin("état_civil"), "étatCivil");
+        assert_eq!(CamelCase.apply_to_variant_builtin("Über"), "Über");
+    }
+
+    #[test]
+    #[cfg(feature = "heck")]
+    fn heck_acronyms() {
+        let heck = |rule| RenameAll { rule, heck: true };
+        assert_eq!(
+            heck(SnakeCase).apply_to_variant("HTTPStatus"),
+            "http_status"
+        );
+        assert_eq!(
+            heck(KebabCase).apply_to_variant("XMLHttpRequest"),
+            "xml-http-request"
+        );
+        assert_eq!(heck(CamelCase).apply_to_variant("HTTPStatus"), "httpStatus");
+        assert_eq!(heck(PascalCase).apply_to_field("http_status"), "HttpStatus");
+        assert_eq!(
+            heck(ScreamingKebabCase).apply_to_field("version2_id"),
+            "VERSION2-ID"
+        );
+        assert_eq!(heck(SnakeCase).apply_to_field("HTTPStatus"), "http_status");
+    }
+
+    #[test]
+    fn serde_acronyms() {
+        assert_eq!(
+            RenameAll::new(SnakeCase).apply_to_variant("HTTPStatus"),
+            "h_t_t_p_status"
+        );
+        assert_eq!(
+            RenameAll::new(CamelCase).apply_to_variant("HTTPStatus"),
+            "hTTPStatus"
+        );
+        assert_eq!(
+            RenameAll::new(SnakeCase).apply_to_field("HTTPStatus"),
+            "HTTPStatus"
+        );
+    }
+
+    #[test]
+    fn words_option() {
+        let parse = |meta: syn::Meta| RenameAll::from_meta(&meta);
+        assert_eq!(
+            parse(parse_quote!(rename_all = "snake_case")).unwrap(),
+            RenameAll::new(SnakeCase)
+        );
+        assert_eq!(
+            parse(parse_quote!(rename_all(
+                rule = "snake_case",
+                words = "serde"
+            )))
+            .unwrap(),
+            RenameAll::new(SnakeCase)
+        );
+        assert_eq!(
+            parse(parse_quote!(rename_all(
+                rule = "snake_case",
+                words = "heck"
+            )))
+            .ok(),
+            Some(RenameAll {
+                rule: SnakeCase,
+                heck: true
+            })
+            .filter(|_| cfg!(feature = "heck"))
+        );
+        assert!(parse(parse_quote!(rename_all(
+            rule = "snake_case",
+            words = "nltk"
+        )))
+        .is_err());
+        assert!(parse(parse_quote!(rename_all(words = "serde"))).is_err());
+    }
+}
@@ -0,0 +1,2784 @@
+use darling::{
+    ast::Data,
+    util::{Override, PathList, SpannedValue},
+    Error, FromDeriveInput, FromField, FromMeta,
+};
+use proc_macro2::{Group, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{
+    ext::IdentExt, parse_quote, spanned::Spanned, Attribute, DeriveInput, Generics, Ident, Item,
+    Lit, Meta, NestedMeta, Path, PathArguments, Type, Visibility, WherePredicate,
+};
+
+use crate::{
+    casing::{titleize, RenameAll, RenameRule},
+    glob::glob_matches,
+    hash::names_hash,
+    is_non_exhaustive,
+    serde::{self, SerdeField},
+};
+
+/// Expands `#[field_names::fields(...)]`, the attribute-macro form of the derive.
+///
+/// The attribute's arguments are treated as a `#[field_names(...)]` attribute on the struct.
+/// Only structs are accepted: an `impl` block doesn't say which fields the struct has.
+pub(crate) fn expand_attribute(args: TokenStream, input: Item) -> TokenStream {
+    let mut item = match input {
+        Item::Struct(item) => item,
+        other => {
+            return Error::custom(
+                "`#[field_names::fields]` can only be applied to a struct; \
+                 it cannot see the fields of a struct from an `impl` block",
+            )
+            .with_span(&other)
+            .write_errors()
+        }
+    };
+
+    let mut input = DeriveInput::from(item.clone());
+    if !args.is_empty() {
+        input.attrs.push(parse_quote!(#[field_names(#args)]));
+    }
+
+    // Unlike a derive, an attribute macro can't register `field_names` as a helper attribute,
+    // so both the struct's and its fields' attributes must be removed from the emitted struct.
+    item.attrs.retain(|attr| !attr.path.is_ident("field_names"));
+    for field in item.fields.iter_mut() {
+        field
+            .attrs
+            .retain(|attr| !attr.path.is_ident("field_names"));
+    }
+
+    match Receiver::from_derive_input(&input) {
+        Ok(receiver) => quote!(#item #receiver),
+        Err(err) => {
+            let errors = err.write_errors();
+            quote!(#item #errors)
+        }
+    }
+}
+
+#[derive(FromDeriveInput)]
+#[darling(
+    attributes(field_names),
+    supports(struct_named),
+    forward_attrs(non_exhaustive, repr, serde),
+    and_then = "Self::validate"
+)]
+pub(crate) struct Receiver {
+    ident: Ident,
+    vis: Visibility,
+    generics: Generics,
+    data: Data<(), ReceiverField>,
+    attrs: Vec<Attribute>,
+    /// Implement `field_names::FieldNames`, or the trait at the given path, in addition to
+    /// emitting the inherent `FIELDS` const.
+    #[darling(default)]
+    impl_trait: Option<Override<Path>>,
+    /// Emit `FIELDS` as `field_names::FieldName` values rather than bare `&str`.
+    #[darling(default)]
+    typed: bool,
+    /// Emit `FIELDS` as an array of this type, built by calling `elem_ctor` on each name.
+    #[darling(default)]
+    elem_type: Option<Type>,
+    /// The `const fn(&'static str) -> T` that builds each `elem_type` element.
+    #[darling(default)]
+    elem_ctor: Option<Path>,
+    /// Emit `FIELDS_JOINED`, the field names concatenated with this separator.
+    #[darling(default)]
+    joined: Option<String>,
+    /// Surround `FIELDS_JOINED` with the first and second halves of this string, e.g. `"[]"`.
+    #[darling(default)]
+    wrap: Option<SpannedValue<String>>,
+    /// Skip fields whose names match any of these globs.
+    #[darling(multiple)]
+    skip_matching: Vec<String>,
+    /// Convert field names with a serde-compatible casing rule. When set, `FIELDS_ORIGINAL` is
+    /// also emitted with the untransformed identifiers.
+    #[darling(default)]
+    rename_all: Option<RenameAll>,
+    /// Prepend the `FIELDS` of this type, which must implement `field_names::FieldNames`.
+    #[darling(default)]
+    extends: Option<Path>,
+    /// Emit `FIELDS_DEPRECATED`, pairing each name with whether the field is `#[deprecated]`.
+    #[darling(default)]
+    with_deprecated: bool,
+    /// Generate `missing_fields`, listing the `Option` fields which are currently `None`.
+    #[darling(default)]
+    missing: bool,
+    /// Generate `type_and_fields`, pairing `core::any::type_name::<Self>()` with the names.
+    #[darling(default)]
+    type_and_fields: bool,
+    /// Skip the named fields, as if each had `#[field_names(skip)]`.
+    #[darling(default)]
+    skip: PathList,
+    /// Emit `FIELDS_LOWER`, the field names with ASCII letters lowercased.
+    #[darling(default)]
+    lowercase_const: bool,
+    /// Generate a fieldless enum with one variant per field, named `{Struct}Field` unless a
+    /// name is given.
+    #[darling(default)]
+    field_enum: Option<Override<Ident>>,
+    /// Only emit fields marked `#[field_names(keep)]`.
+    #[darling(default)]
+    opt_in: bool,
+    /// Generate `set_field_from_str`, which parses a value into the named field.
+    #[darling(default)]
+    setters: bool,
+    /// Skip fields marked `#[doc(hidden)]`.
+    #[darling(default)]
+    skip_doc_hidden: bool,
+    /// Emit `FIELD_NAME_LENS`, the byte length of each emitted name.
+    #[darling(default)]
+    name_lens: bool,
+    /// Generate `fields()`, returning the names wrapped in this type.
+    #[darling(default)]
+    wrapper: Option<Wrapper>,
+    /// Fail compilation if no fields are emitted.
+    #[darling(default)]
+    validate: bool,
+    /// Emit `FIELDS_BYTES`, the names as byte slices.
+    #[darling(default)]
+    bytes: bool,
+    /// Require a `#[repr]` whose field order is the declaration order, so `FIELDS` is in
+    /// layout order.
+    #[darling(default)]
+    layout_order: SpannedValue<bool>,
+    /// Apply the `skip`, `rename`, and `rename_all` options of the struct's serde attributes.
+    #[darling(default)]
+    from_serde: bool,
+    /// What to do with fields that have `#[serde(flatten)]`, under `from_serde`.
+    #[darling(default)]
+    flatten_serde: Option<SpannedValue<FlattenSerde>>,
+    /// Generate `changed_fields`, listing the fields that differ between two values.
+    #[darling(default)]
+    diff: bool,
+    /// Prepend this to every emitted name, after any renaming.
+    #[darling(default)]
+    prefix: Option<String>,
+    /// Append this to every emitted name, after any renaming.
+    #[darling(default)]
+    suffix: Option<String>,
+    /// Generate a `{Struct}Fields` bitset, with one bit per emitted field.
+    #[darling(default)]
+    bitset: SpannedValue<bool>,
+    /// Only emit fields whose type path ends with one of these, such as `"String"`.
+    #[darling(multiple)]
+    only_type: Vec<String>,
+    /// Emit a module-level `{STRUCT}_FIELD_COUNT` const, for places an associated const can't
+    /// be used, such as const generic arguments.
+    #[darling(default)]
+    count_const: bool,
+    /// Emit `FIELD_LOCATIONS`, the source location of each emitted field.
+    #[darling(default)]
+    with_span_info: bool,
+    /// Generate `field_index` and `contains_field`, which look names up after lowercasing both
+    /// the name and the emitted names. `FIELDS` keeps its casing.
+    #[darling(default)]
+    lowercase_lookup: bool,
+    /// Generate `fields_with_type_ids`, pairing each name with its field's `TypeId`.
+    #[darling(default)]
+    type_ids: SpannedValue<bool>,
+    /// Emit every name, and every array parallel to `FIELDS`, in reverse declaration order.
+    #[darling(default)]
+    reverse: SpannedValue<bool>,
+    /// Generate a test asserting that the struct's names, less `omit`, are the names of this
+    /// type, which must also derive `FieldNames`.
+    #[darling(default)]
+    sync_with: Option<Path>,
+    /// Names `sync_with` expects to be absent from the other type.
+    #[darling(multiple)]
+    omit: Vec<String>,
+    /// Give skipped fields a row in `FIELD_LOCATIONS` and `fields_with_type_ids` too, so rows
+    /// line up with the struct's declared fields, and emit `FIELDS_SKIPPED` to flag them.
+    #[darling(default)]
+    preserve_skipped_rows: SpannedValue<bool>,
+    /// Generate `type_path`, the struct's name qualified by the path of its module.
+    #[darling(default)]
+    with_module_path: bool,
+    /// Emit `TYPE_NAME`, the struct's declared identifier.
+    #[darling(default)]
+    with_type_name: bool,
+    /// Emit `NON_EXHAUSTIVE`, whether the struct is marked `#[non_exhaustive]`.
+    #[darling(default)]
+    with_non_exhaustive: bool,
+    /// Generate `source_file`, the `file!()` of the file that defines the struct.
+    #[darling(default)]
+    with_source_file: bool,
+    /// Emit `FIELD_ATTRS`, pairing each name with the field's other attributes as strings.
+    #[darling(default)]
+    with_attrs: bool,
+    /// Emit `FIELD_LABELS`, the names as human-readable words, e.g. `Minutes To Midnight`.
+    #[darling(default)]
+    titleize: bool,
+    /// Generate `from_pairs`, which collects string values by field name into a generated
+    /// `Partial{Struct}`. Requires the `from_pairs` feature.
+    #[darling(default)]
+    from_pairs: SpannedValue<bool>,
+    /// Generate `field_values`, pairing each name with the field's value converted into this
+    /// type, which every emitted field must be `Copy` and `Into`.
+    #[darling(default)]
+    values_as: Option<Type>,
+    /// Emit these fields first, in the order given, ahead of the rest.
+    #[darling(default)]
+    first: PathList,
+    /// Emit `CSV_COLUMNS`, pairing each name with its one-based column number.
+    #[darling(default)]
+    csv: bool,
+    /// Emit an extra const of the names for each casing rule, under the given const name.
+    #[darling(default)]
+    also: AlsoConsts,
+    /// Emit `FIELDS_HASH`, a stable hash of the emitted names, for detecting schema drift.
+    #[darling(default)]
+    hash: bool,
+    /// Generate `fields_implementing`, listing the emitted fields whose types implement this
+    /// trait.
+    #[darling(default)]
+    only_impl: Option<Path>,
+    /// Emit `FIELDS` wrapped in this tuple struct, whose only field is the array.
+    #[darling(default)]
+    wrap_in: Option<Path>,
+    /// Emit `FIELD_INITIALS`, abbreviating each name to its first character, or with
+    /// `initials = "words"` to the capitalized first letter of each word.
+    #[darling(default)]
+    initials: Option<Override<Initials>>,
+    /// Emit `FIELDS_DEFAULTABLE`, pairing each name with whether serde can default the field,
+    /// from `#[serde(default)]` on the field or the struct.
+    #[darling(default)]
+    with_defaultable: bool,
+}
+
+/// The handling of `#[serde(flatten)]` fields, which have no wire name of their own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FlattenSerde {
+    /// Leave the field out of the names.
+    Skip,
+    /// Splice in the `FIELDS` of the field's type, which must implement `FieldNames`.
+    Recurse,
+}
+
+impl FromMeta for FlattenSerde {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        match value {
+            "skip" => Ok(FlattenSerde::Skip),
+            "recurse" => Ok(FlattenSerde::Recurse),
+            _ => Err(Error::unknown_value(value)),
+        }
+    }
+}
+
+/// An owned-or-borrowed string type for `#[field_names(wrapper = "...")]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Wrapper {
+    Cow,
+}
+
+impl FromMeta for Wrapper {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        if value.eq_ignore_ascii_case("cow") {
+            Ok(Wrapper::Cow)
+        } else {
+            Err(Error::unknown_value(value))
+        }
+    }
+}
+
+/// The consts for `#[field_names(also(camelCase = "FIELDS_CAMEL", ...))]`, each pairing a
+/// casing rule with the name of the const that holds the names in that casing.
+///
+/// Meta items need a path before the `=`, so rules containing `-` are written with `_`
+/// instead, e.g. `kebab_case = "FIELDS_KEBAB"`.
+#[derive(Debug, Default)]
+struct AlsoConsts(Vec<(RenameRule, Ident)>);
+
+impl FromMeta for AlsoConsts {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        let mut errors = Vec::new();
+        let mut consts = Vec::new();
+        for item in items {
+            let value = match item {
+                NestedMeta::Meta(Meta::NameValue(value)) => value,
+                _ => {
+                    errors.push(Error::custom("expected `rule = \"CONST_NAME\"`").with_span(item));
+                    continue;
+                }
+            };
+
+            let key = value
+                .path
+                .get_ident()
+                .map(|ident| ident.to_string())
+                .unwrap_or_default();
+            let rule = RenameRule::from_string(&key)
+                .or_else(|err| RenameRule::from_string(&key.replace('_', "-")).map_err(|_| err))
+                .map_err(|err| err.with_span(&value.path));
+            let ident = Ident::from_value(&value.lit).map_err(|err| err.with_span(&value.lit));
+            match (rule, ident) {
+                (Ok(rule), Ok(ident)) => consts.push((rule, ident)),
+                (rule, ident) => errors.extend(rule.err().into_iter().chain(ident.err())),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(AlsoConsts(consts))
+        } else {
+            Err(Error::multiple(errors))
+        }
+    }
+}
+
+/// How `#[field_names(initials)]` abbreviates each name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Initials {
+    /// The name's first character, e.g. `d` for `display_name`.
+    First,
+    /// The first letter of each word, capitalized, e.g. `DN` for `display_name`.
+    Words,
+}
+
+impl Initials {
+    fn abbreviate(self, name: &str) -> String {
+        match self {
+            Initials::First => name.chars().take(1).collect(),
+            Initials::Words => titleize(name)
+                .split(' ')
+                .filter_map(|word| word.chars().next())
+                .collect(),
+        }
+    }
+}
+
+impl FromMeta for Initials {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        match value {
+            "first" => Ok(Initials::First),
+            "words" => Ok(Initials::Words),
+            _ => Err(Error::unknown_value(value)),
+        }
+    }
+}
+
+impl Receiver {
+    fn validate(mut self) -> darling::Result<Self> {
+        match (&self.elem_type, &self.elem_ctor) {
+            (Some(elem_type), None) => {
+                return Err(Error::custom("`elem_type` requires `elem_ctor`").with_span(elem_type));
+            }
+            (None, Some(elem_ctor)) => {
+                return Err(Error::custom("`elem_ctor` requires `elem_type`").with_span(elem_ctor));
+            }
+            (Some(elem_type), Some(_)) if self.typed => {
+                return Err(Error::custom("`typed` cannot be combined with `elem_type`")
+                    .with_span(elem_type));
+            }
+            _ => {}
+        }
+
+        if *self.preserve_skipped_rows && !(self.with_span_info || *self.type_ids) {
+            return Err(Error::custom(
+                "`preserve_skipped_rows` requires `with_span_info` or `type_ids`",
+            )
+            .with_span(&self.preserve_skipped_rows));
+        }
+
+        if *self.from_pairs && !cfg!(feature = "from_pairs") {
+            return Err(Error::custom(
+                "`from_pairs` requires the `from_pairs` feature of `field_names`",
+            )
+            .with_span(&self.from_pairs));
+        }
+
+        if !self.omit.is_empty() && self.sync_with.is_none() {
+            return Err(Error::custom("`omit` requires `sync_with`"));
+        }
+
+        if let Some(wrap) = &self.wrap {
+            if self.joined.is_none() {
+                return Err(Error::custom("`wrap` requires `joined`").with_span(wrap));
+            }
+
+            if wrap.chars().count() % 2 != 0 {
+                return Err(Error::custom(
+                    "`wrap` must have an even number of characters, such as \"[]\"",
+                )
+                .with_span(wrap));
+            }
+        }
+
+        if self.from_serde {
+            if self.rename_all.is_none() {
+                self.rename_all = serde::rename_all(&self.attrs).map(RenameAll::new);
+            }
+
+            if let Data::Struct(fields) = &mut self.data {
+                for field in &mut fields.fields {
+                    field.serde = SerdeField::from_attrs(&field.attrs);
+                }
+            }
+        } else if let Some(flatten_serde) = &self.flatten_serde {
+            return Err(
+                Error::custom("`flatten_serde` requires `from_serde`").with_span(flatten_serde)
+            );
+        }
+
+        let fields = self
+            .data
+            .as_ref()
+            .take_struct()
+            .expect("FieldNames only supports named structs");
+        if !self.opt_in {
+            let stray_keeps = fields
+                .iter()
+                .filter(|field| field.keep)
+                .map(|field| {
+                    Error::custom("`keep` requires `#[field_names(opt_in)]` on the struct")
+                        .with_span(&field.ident)
+                })
+                .collect::<Vec<_>>();
+            if !stray_keeps.is_empty() {
+                return Err(Error::multiple(stray_keeps));
+            }
+        }
+
+        let unknown_skips = self
+            .skip
+            .iter()
+            .filter(|path| !fields.iter().any(|field| field.is_named(path)))
+            .map(|path| Error::custom("no field with this name").with_span(path))
+            .collect::<Vec<_>>();
+        if !unknown_skips.is_empty() {
+            return Err(Error::multiple(unknown_skips));
+        }
+
+        let nested_source = if self.extends.is_some() {
+            Some("extends")
+        } else if self.nested_names().is_empty() {
+            None
+        } else {
+            Some("flatten_serde = \"recurse\"")
+        };
+
+        if let Some(source) = nested_source {
+            // These are built from the struct's own fields at expansion time, so they cannot
+            // include the names contributed by other types.
+            let conflicts = [
+                ("joined", self.joined.is_some()),
+                ("with_deprecated", self.with_deprecated),
+                ("lowercase_const", self.lowercase_const),
+                ("field_enum", self.field_enum.is_some()),
+                ("name_lens", self.name_lens),
+                ("bitset", *self.bitset),
+                ("with_span_info", self.with_span_info),
+                ("type_ids", *self.type_ids),
+                ("reverse", *self.reverse),
+                ("with_attrs", self.with_attrs),
+                ("titleize", self.titleize),
+                ("from_pairs", *self.from_pairs),
+                ("values_as", self.values_as.is_some()),
+                ("csv", self.csv),
+                ("also", !self.also.0.is_empty()),
+                ("hash", self.hash),
+                ("only_impl", self.only_impl.is_some()),
+                ("initials", self.initials.is_some()),
+                ("with_defaultable", self.with_defaultable),
+            ];
+
+            let errors = conflicts
+                .iter()
+                .filter(|(_, set)| *set)
+                .map(|(option, _)| {
+                    Error::custom(format!("`{}` cannot be combined with `{}`", option, source))
+                })
+                .collect::<Vec<_>>();
+
+            if !errors.is_empty() {
+                return Err(Error::multiple(errors));
+            }
+
+            // The length of `FIELDS` includes the nested types' `FIELDS`, and array lengths
+            // can't depend on generic parameters.
+            let generic_sources = self
+                .nested_names()
+                .into_iter()
+                .filter(|(_, ty)| self.mentions_generic_param(ty.clone()))
+                .map(|(_, ty)| {
+                    Error::custom(format!(
+                        "`{}` can't use the generic parameters of `{}`, since the length of \
+                         `FIELDS` would depend on them",
+                        source, self.ident
+                    ))
+                    .with_span(&ty)
+                })
+                .collect::<Vec<_>>();
+            if !generic_sources.is_empty() {
+                return Err(Error::multiple(generic_sources));
+            }
+        }
+
+        if *self.type_ids {
+            if let Some(lifetime) = self.generics.lifetimes().next() {
+                return Err(Error::custom(format!(
+                    "`type_ids` requires `'static` field types, so `{}` can't have lifetime `{}`",
+                    self.ident, lifetime.lifetime
+                ))
+                .with_span(&lifetime.lifetime));
+            }
+        }
+
+        if self.field_enum.is_some() {
+            let errors = self
+                .emitted_fields()
+                .into_iter()
+                .filter_map(|field| field.enum_variant_ident().err())
+                .collect::<Vec<_>>();
+            if !errors.is_empty() {
+                return Err(Error::multiple(errors));
+            }
+        }
+
+        // `Partial{Struct}` borrows its values for `'pairs`, which it would never use without
+        // a field to hold one.
+        if *self.from_pairs && self.emitted_fields().is_empty() {
+            return Err(
+                Error::custom("`from_pairs` requires at least one emitted field")
+                    .with_span(&self.from_pairs),
+            );
+        }
+
+        if *self.bitset && self.emitted_fields().len() > 128 {
+            return Err(
+                Error::custom("`bitset` supports at most 128 fields").with_span(&self.bitset)
+            );
+        }
+
+        let generated = self.generated_items();
+        for (i, (_, const_ident)) in self.also.0.iter().enumerate() {
+            let message =
+                if let Some((_, option)) = generated.iter().find(|(name, _)| const_ident == name) {
+                    format!(
+                        "`also` would emit `{}`, which is already {}",
+                        const_ident,
+                        match option {
+                            Some(option) => format!("emitted by `{}`", option),
+                            None => "emitted for every struct".to_string(),
+                        }
+                    )
+                } else if self.also.0[..i]
+                    .iter()
+                    .any(|(_, other)| other == const_ident)
+                {
+                    format!("`also` names `{}` more than once", const_ident)
+                } else {
+                    continue;
+                };
+            return Err(Error::custom(message).with_span(const_ident));
+        }
+
+        let generated_types = self.generated_types();
+        for (i, (option, ident)) in generated_types.iter().enumerate() {
+            let clash = if *ident == self.ident {
+                Some("the struct's name".to_string())
+            } else {
+                generated_types[..i]
+                    .iter()
+                    .find(|(_, other)| other == ident)
+                    .map(|(other, _)| format!("the name of the type generated by `{}`", other))
+            };
+            if let Some(clash) = clash {
+                return Err(Error::custom(format!(
+                    "`{}` would generate a type named `{}`, which is already {}",
+                    option, ident, clash
+                ))
+                .with_span(ident));
+            }
+        }
+
+        if *self.layout_order && !self.has_declared_layout() {
+            return Err(Error::custom(
+                "`layout_order` requires `#[repr(C)]` or `#[repr(transparent)]`; \
+                 the field order of other layouts is unspecified",
+            )
+            .with_span(&self.layout_order));
+        }
+
+        let emitted = self.emitted_fields();
+        let bad_firsts = self
+            .first
+            .iter()
+            .filter_map(|path| {
+                if !fields.iter().any(|field| field.is_named(path)) {
+                    Some(Error::custom("no field with this name").with_span(path))
+                } else if !emitted.iter().any(|field| field.is_named(path)) {
+                    Some(Error::custom("`first` can't pin a skipped field").with_span(path))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        if !bad_firsts.is_empty() {
+            return Err(Error::multiple(bad_firsts));
+        }
+
+        if *self.layout_order && !self.first.is_empty() {
+            return Err(
+                Error::custom("`first` cannot be combined with `layout_order`")
+                    .with_span(&self.layout_order),
+            );
+        }
+
+        if *self.layout_order {
+            let prioritized = fields
+                .iter()
+                .filter(|field| field.priority != 0)
+                .map(|field| {
+                    Error::custom("`priority` cannot be combined with `layout_order`")
+                        .with_span(&field.ident)
+                })
+                .collect::<Vec<_>>();
+            if !prioritized.is_empty() {
+                return Err(Error::multiple(prioritized));
+            }
+        }
+
+        if *self.layout_order && *self.reverse {
+            return Err(
+                Error::custom("`reverse` cannot be combined with `layout_order`")
+                    .with_span(&self.reverse),
+            );
+        }
+
+        // Other types may contribute the fields, so the check waits until their `FIELDS` are
+        // known; see `to_tokens`.
+        if self.validate && self.nested_names().is_empty() && self.emitted_fields().is_empty() {
+            return Err(Error::custom(
+                "`validate` requires at least one field to be emitted, but every field is skipped",
+            )
+            .with_span(&self.ident));
+        }
+
+        Ok(self)
+    }
+
+    /// The items of the struct's `#[repr(...)]` attributes.
+    fn repr_items(&self) -> Vec<NestedMeta> {
+        self.attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("repr"))
+            .filter_map(|attr| match attr.parse_meta() {
+                Ok(Meta::List(list)) => Some(list.nested),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// Whether the struct's `#[repr]` lays fields out in declaration order.
+    fn has_declared_layout(&self) -> bool {
+        self.repr_items().iter().any(|nested| {
+            matches!(
+                nested,
+                NestedMeta::Meta(Meta::Path(path))
+                    if path.is_ident("C") || path.is_ident("transparent")
+            )
+        })
+    }
+
+    /// Whether the struct is `#[repr(packed)]` or `#[repr(packed(N))]`, so its fields may be
+    /// unaligned and can't be borrowed.
+    fn is_packed(&self) -> bool {
+        self.repr_items().iter().any(|nested| match nested {
+            NestedMeta::Meta(meta) => meta.path().is_ident("packed"),
+            NestedMeta::Lit(_) => false,
+        })
+    }
+
+    /// Reads `field` of `receiver`. Comparing or calling a method on a field borrows it, which
+    /// isn't allowed for packed structs, so there the field is copied out first, as the standard
+    /// derives do; such fields must be `Copy`.
+    fn field_value(&self, receiver: TokenStream, field: &ReceiverField) -> TokenStream {
+        let ident = &field.ident;
+        if self.is_packed() {
+            quote!({ #receiver.#ident })
+        } else {
+            quote!(#receiver.#ident)
+        }
+    }
+
+    fn trait_path(&self) -> Option<TokenStream> {
+        self.impl_trait.as_ref().map(|impl_trait| match impl_trait {
+            Override::Inherit => quote!(::field_names::FieldNames),
+            Override::Explicit(path) => quote!(#path),
+        })
+    }
+
+    /// Builds the length and value of an array of `names`, splicing in the `FIELDS` of any
+    /// types from `nested_names`. `wrap` converts a `&'static str` expression into an element
+    /// of type `elem_ty`.
+    fn names_array(
+        &self,
+        names: &[String],
+        elem_ty: &TokenStream,
+        wrap: impl Fn(TokenStream) -> TokenStream,
+    ) -> (TokenStream, TokenStream) {
+        let own_len = names.len();
+        let own = names
+            .iter()
+            .map(|name| wrap(quote!(#name)))
+            .collect::<Vec<_>>();
+
+        let nested = self.nested_names();
+        if nested.is_empty() {
+            return (quote!(#own_len), quote!([#(#own),*]));
+        }
+
+        let mut len = quote!(#own_len);
+        let mut steps = Vec::new();
+        let mut start = 0;
+        let nested_elem = wrap(quote!(nested[j]));
+        for (position, ty) in nested {
+            let chunk = &own[start..position];
+            let offsets = 0..chunk.len();
+            let chunk_len = chunk.len();
+            steps.push(quote! {
+                #(names[i + #offsets] = #chunk;)*
+                i += #chunk_len;
+            });
+            start = position;
+
+            let fields = quote!(<#ty as ::field_names::FieldNames>::FIELDS);
+            len.extend(quote!(+ #fields.len()));
+            steps.push(quote! {
+                let nested = #fields;
+                let mut j = 0;
+                while j < nested.len() {
+                    names[i] = #nested_elem;
+                    i += 1;
+                    j += 1;
+                }
+            });
+        }
+        let chunk = &own[start..];
+        let offsets = 0..chunk.len();
+        steps.push(quote! {
+            #(names[i + #offsets] = #chunk;)*
+        });
+
+        let placeholder = wrap(quote!(""));
+        let value = quote!({
+            let mut names: [#elem_ty; #len] = [#placeholder; #len];
+            let mut i = 0;
+            #(#steps)*
+            names
+        });
+
+        (len, value)
+    }
+
+    /// Types whose `FieldNames::FIELDS` are spliced into this struct's names, each with the
+    /// number of the struct's own emitted names that come before it: first the `extends` base,
+    /// then every `#[serde(flatten)]` field under `flatten_serde = "recurse"`.
+    fn nested_names(&self) -> Vec<(usize, TokenStream)> {
+        let mut nested = Vec::new();
+        if let Some(base) = &self.extends {
+            nested.push((0, quote!(#base)));
+        }
+
+        let mut position = 0;
+        for field in self.included_fields() {
+            if self.is_recursed(field) {
+                let ty = &field.ty;
+                nested.push((position, quote!(#ty)));
+            } else {
+                position += 1;
+            }
+        }
+
+        nested
+    }
+
+    /// Whether `tokens` mention one of the struct's generic parameters, including lifetimes.
+    fn mentions_generic_param(&self, tokens: TokenStream) -> bool {
+        let mut after_quote = false;
+        tokens.into_iter().any(|token| {
+            let is_lifetime = after_quote;
+            after_quote = matches!(&token, TokenTree::Punct(punct) if punct.as_char() == '\'');
+            match token {
+                TokenTree::Group(group) => self.mentions_generic_param(group.stream()),
+                TokenTree::Ident(ident) if is_lifetime => self
+                    .generics
+                    .lifetimes()
+                    .any(|param| param.lifetime.ident == ident),
+                TokenTree::Ident(ident) => {
+                    self.generics
+                        .type_params()
+                        .any(|param| param.ident == ident)
+                        || self
+                            .generics
+                            .const_params()
+                            .any(|param| param.ident == ident)
+                }
+                _ => false,
+            }
+        })
+    }
+
+    fn flatten_serde(&self) -> FlattenSerde {
+        self.flatten_serde
+            .as_ref()
+            .map_or(FlattenSerde::Skip, |mode| **mode)
+    }
+
+    /// Whether the field's names come from its type's `FIELDS`, rather than its own name.
+    fn is_recursed(&self, field: &ReceiverField) -> bool {
+        field.serde.flatten && self.flatten_serde() == FlattenSerde::Recurse
+    }
+
+    /// An expression of type `&'static [&'static str]` holding `names`, usable in function
+    /// bodies where the array from `names_array` would not be promoted to a `'static` borrow.
+    fn names_slice(&self, names: &[String]) -> TokenStream {
+        let str_ty = quote!(&'static str);
+        let (len, value) = self.names_array(names, &str_ty, |name| name);
+        quote!({
+            const NAMES: [&'static str; #len] = #value;
+            &NAMES
+        })
+    }
+
+    /// The struct's own emitted fields, excluding the ones whose names come from their type,
+    /// in the order their names are emitted.
+    fn emitted_fields(&self) -> Vec<&ReceiverField> {
+        let mut fields = self
+            .included_fields()
+            .into_iter()
+            .filter(|field| !self.is_recursed(field))
+            .collect::<Vec<_>>();
+        if *self.reverse {
+            fields.reverse();
+        }
+        // The sort is stable, so fields of equal priority keep their relative order.
+        fields.sort_by_key(|field| field.priority);
+        self.pin_first(fields, |field| field)
+    }
+
+    /// Moves the items for the fields named by `first` to the front, in the order `first`
+    /// lists them, leaving the order of the rest alone.
+    fn pin_first<'a, T>(&self, items: Vec<T>, field: impl Fn(&T) -> &'a ReceiverField) -> Vec<T> {
+        let position = |item: &T| {
+            self.first
+                .iter()
+                .position(|path| field(item).is_named(path))
+        };
+        let (mut pinned, rest): (Vec<_>, Vec<_>) =
+            items.into_iter().partition(|item| position(item).is_some());
+        pinned.sort_by_key(|item| position(item));
+        pinned.extend(rest);
+        pinned
+    }
+
+    /// The fields which aren't skipped by any option.
+    fn included_fields(&self) -> Vec<&ReceiverField> {
+        self.data
+            .as_ref()
+            .take_struct()
+            .expect("FieldNames only supports named structs")
+            .into_iter()
+            .filter(|field| !field.skip && !field.serde.skip)
+            .filter(|field| {
+                self.only_type.is_empty()
+                    || self.only_type.iter().any(|ty| field.type_ends_with(ty))
+            })
+            .filter(|field| !field.serde.flatten || self.flatten_serde() == FlattenSerde::Recurse)
+            .filter(|field| !(self.skip_doc_hidden && field.is_doc_hidden()))
+            .filter(|field| !self.opt_in || field.keep)
+            .filter(|field| !self.skip.iter().any(|path| field.is_named(path)))
+            .filter(|field| {
+                let name = field.original_name();
+                !self
+                    .skip_matching
+                    .iter()
+                    .any(|pattern| glob_matches(pattern, &name))
+            })
+            .collect()
+    }
+
+    /// The fields with a row in the metadata arrays, each paired with whether it's skipped.
+    /// Under `preserve_skipped_rows` that's every declared field, otherwise the emitted ones.
+    fn metadata_rows(&self) -> Vec<(&ReceiverField, bool)> {
+        let emitted = self.emitted_fields();
+        if !*self.preserve_skipped_rows {
+            return emitted.into_iter().map(|field| (field, false)).collect();
+        }
+
+        let mut rows = self
+            .data
+            .as_ref()
+            .take_struct()
+            .expect("FieldNames only supports named structs")
+            .into_iter()
+            .map(|field| (field, !emitted.iter().any(|e| std::ptr::eq(*e, field))))
+            .collect::<Vec<_>>();
+        if *self.reverse {
+            rows.reverse();
+        }
+        rows.sort_by_key(|(field, _)| field.priority);
+        self.pin_first(rows, |(field, _)| field)
+    }
+
+    /// Whether any emitted field is assigned to a group, which enables `fields_in_group`.
+    fn has_groups(&self) -> bool {
+        self.emitted_fields()
+            .into_iter()
+            .any(|field| field.group.is_some())
+    }
+
+    /// The emitted name of `field`. In order of precedence, the name comes from the field's
+    /// `rename`, its `#[serde(rename)]` under `from_serde`, or `rename_all` applied to the
+    /// field's identifier; `prefix` and `suffix` are then added to whichever was chosen.
+    fn field_name(&self, field: &ReceiverField) -> String {
+        self.field_name_with(field, self.rename_all)
+    }
+
+    /// The emitted name of `field`, with `rule` in place of `rename_all`.
+    fn field_name_with(&self, field: &ReceiverField, rule: Option<RenameAll>) -> String {
+        let name = field.name(rule);
+        format!(
+            "{}{}{}",
+            self.prefix.as_deref().unwrap_or_default(),
+            name,
+            self.suffix.as_deref().unwrap_or_default()
+        )
+    }
+
+    fn fields_to_emit(&self) -> Vec<String> {
+        self.emitted_fields()
+            .into_iter()
+            .map(|field| self.field_name(field))
+            .collect()
+    }
+
+    /// The `(identifier, emitted name)` pairs of the emitted fields whose names differ from
+    /// their Rust identifiers, and how many there are.
+    fn renamed_fields(&self) -> (usize, Vec<TokenStream>) {
+        let renamed = self
+            .original_fields_to_emit()
+            .into_iter()
+            .zip(self.fields_to_emit())
+            .filter(|(original, emitted)| original != emitted)
+            .map(|(original, emitted)| quote!(#original, #emitted))
+            .collect::<Vec<_>>();
+        (renamed.len(), renamed)
+    }
+
+    fn original_fields_to_emit(&self) -> Vec<String> {
+        self.emitted_fields()
+            .into_iter()
+            .map(ReceiverField::original_name)
+            .collect()
+    }
+}
+
+impl Receiver {
+    fn field_enum_ident(&self) -> Option<Ident> {
+        self.field_enum.as_ref().map(|field_enum| match field_enum {
+            Override::Inherit => format_ident!("{}Field", self.ident),
+            Override::Explicit(ident) => ident.clone(),
+        })
+    }
+
+    fn bitset_ident(&self) -> Ident {
+        format_ident!("{}Fields", self.ident)
+    }
+
+    fn partial_ident(&self) -> Ident {
+        format_ident!("Partial{}", self.ident)
+    }
+
+    /// The names of the consts and methods generated on the struct itself, other than the
+    /// ones listed in `also`, each with the option that generates it, if any.
+    fn generated_items(&self) -> Vec<(&'static str, Option<&'static str>)> {
+        let mut items = ["FIELDS", "all_field_names"]
+            .iter()
+            .map(|name| (*name, None))
+            .collect::<Vec<_>>();
+        let emitted = self.emitted_fields();
+        if !emitted.is_empty() {
+            items.push(("FIELD_COUNT_NZ", None));
+        }
+        if cfg!(feature = "serde_json") {
+            items.push(("fields_as_json_value", None));
+        }
+        let optional = [
+            ("fields", "wrapper", self.wrapper.is_some()),
+            ("type_path", "with_module_path", self.with_module_path),
+            ("TYPE_NAME", "with_type_name", self.with_type_name),
+            (
+                "NON_EXHAUSTIVE",
+                "with_non_exhaustive",
+                self.with_non_exhaustive,
+            ),
+            ("source_file", "with_source_file", self.with_source_file),
+            ("FIELDS_ORIGINAL", "rename_all", self.rename_all.is_some()),
+            ("FIELDS_BYTES", "bytes", self.bytes),
+            ("FIELDS_LOWER", "lowercase_const", self.lowercase_const),
+            ("FIELD_NAME_LENS", "name_lens", self.name_lens),
+            ("fields_implementing", "only_impl", self.only_impl.is_some()),
+            ("FIELD_INITIALS", "initials", self.initials.is_some()),
+            ("FIELDS_HASH", "hash", self.hash),
+            ("CSV_COLUMNS", "csv", self.csv),
+            ("FIELD_LOCATIONS", "with_span_info", self.with_span_info),
+            ("FIELDS_DEPRECATED", "with_deprecated", self.with_deprecated),
+            (
+                "FIELDS_DEFAULTABLE",
+                "with_defaultable",
+                self.with_defaultable,
+            ),
+            ("FIELDS_JOINED", "joined", self.joined.is_some()),
+            ("missing_fields", "missing", self.missing),
+            // Emitted whenever a name changes, so the clash is reported against every option
+            // that can change one.
+            (
+                "RENAMED_FIELDS",
+                "rename`, `rename_all`, `prefix` or `suffix",
+                self.renamed_fields().0 > 0,
+            ),
+            (
+                "TAGGED_FIELDS",
+                "tag",
+                emitted.iter().any(|field| field.tag),
+            ),
+            (
+                "SETTABLE_FIELDS",
+                "settable",
+                emitted.iter().any(|field| field.settable),
+            ),
+            ("fields_in_group", "group", self.has_groups()),
+            ("type_and_fields", "type_and_fields", self.type_and_fields),
+            ("FIELD_LABELS", "titleize", self.titleize),
+            ("FIELD_ATTRS", "with_attrs", self.with_attrs),
+            (
+                "FIELDS_SKIPPED",
+                "preserve_skipped_rows",
+                *self.preserve_skipped_rows,
+            ),
+            ("field_index", "lowercase_lookup", self.lowercase_lookup),
+            ("contains_field", "lowercase_lookup", self.lowercase_lookup),
+            ("changed_fields", "diff", self.diff),
+            ("field_values", "values_as", self.values_as.is_some()),
+            ("fields_with_type_ids", "type_ids", *self.type_ids),
+            ("from_pairs", "from_pairs", *self.from_pairs),
+            ("set_field_from_str", "setters", self.setters),
+        ];
+        items.extend(
+            optional
+                .iter()
+                .filter(|(_, _, set)| *set)
+                .map(|(name, option, _)| (*name, Some(*option))),
+        );
+        items
+    }
+
+    /// The types generated next to the struct, with the option that generates each.
+    fn generated_types(&self) -> Vec<(&'static str, Ident)> {
+        let mut types = Vec::new();
+        if let Some(ident) = self.field_enum_ident() {
+            types.push(("field_enum", ident));
+        }
+        if *self.bitset {
+            types.push(("bitset", self.bitset_ident()));
+        }
+        if *self.from_pairs {
+            types.push(("from_pairs", self.partial_ident()));
+        }
+        types
+    }
+
+    /// The enum generated by `field_enum`. Variant identifiers are the `PascalCase` form of
+    /// the Rust field names, so they stay valid identifiers regardless of `rename_all`, while
+    /// `as_str` and `Display` produce the emitted names.
+    fn field_enum(&self) -> Option<TokenStream> {
+        let enum_ident = self.field_enum_ident()?;
+        let vis = &self.vis;
+        let fields = self.emitted_fields();
+        let variants = fields
+            .iter()
+            .map(|field| {
+                field
+                    .enum_variant_ident()
+                    .expect("variant identifiers are checked in `validate`")
+            })
+            .collect::<Vec<_>>();
+        let names = fields
+            .iter()
+            .map(|field| self.field_name(field))
+            .collect::<Vec<_>>();
+        let enum_doc = format!("The fields of `{}`.", self.ident);
+        let variant_docs = names.iter().map(|name| format!("The `{}` field.", name));
+
+        Some(quote! {
+            // The derives are spelled out in full so a caller's own `Debug` or `Hash` items
+            // can't shadow them, and unused variants are expected when only some are matched.
+            #[doc = #enum_doc]
+            #[derive(
+                ::core::fmt::Debug,
+                ::core::clone::Clone,
+                ::core::marker::Copy,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+                ::core::hash::Hash,
+            )]
+            #[allow(dead_code)]
+            #vis enum #enum_ident {
+                #(
+                    #[doc = #variant_docs]
+                    #variants
+                ),*
+            }
+
+            #[automatically_derived]
+            impl #enum_ident {
+                /// The emitted name of this field.
+                pub fn as_str(&self) -> &'static str {
+                    match self {
+                        #(Self::#variants => #names),*
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl ::core::fmt::Display for #enum_ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.write_str(self.as_str())
+                }
+            }
+        })
+    }
+
+    /// Generates the `{Struct}Fields` bitset, backed by a `u64` or, for more than 64 fields,
+    /// a `u128`.
+    fn bitset(&self) -> Option<TokenStream> {
+        if !*self.bitset {
+            return None;
+        }
+
+        let set_ident = self.bitset_ident();
+        let vis = &self.vis;
+        let fields = self.emitted_fields();
+        let (bits_ty, width) = if fields.len() > 64 {
+            (quote!(u128), 128)
+        } else {
+            (quote!(u64), 64)
+        };
+        let consts = fields.iter().map(|field| field.const_ident());
+        let docs = fields
+            .iter()
+            .map(|field| format!("The bit for the `{}` field.", self.field_name(field)));
+        let shifts = 0..fields.len();
+        // Shifting by the full width overflows, so a set that fills its backing type uses `MAX`.
+        let all = if fields.len() == width {
+            quote!(#bits_ty::MAX)
+        } else {
+            let len = fields.len();
+            quote!((1 << #len) - 1)
+        };
+        let doc = format!(
+            "A set of the fields of `{}`, stored as one bit per field.",
+            self.ident
+        );
+
+        Some(quote! {
+            #[doc = #doc]
+            #[derive(
+                ::core::fmt::Debug,
+                ::core::clone::Clone,
+                ::core::marker::Copy,
+                ::core::default::Default,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+                ::core::hash::Hash,
+            )]
+            #vis struct #set_ident(#bits_ty);
+
+            #[automatically_derived]
+            #[allow(dead_code)]
+            impl #set_ident {
+                #(
+                    #[doc = #docs]
+                    pub const #consts: Self = Self(1 << #shifts);
+                )*
+
+                /// The set with no fields.
+                pub const fn empty() -> Self {
+                    Self(0)
+                }
+
+                /// The set with every field.
+                pub const fn all() -> Self {
+                    Self(#all)
+                }
+
+                /// The raw bits, where bit `i` is the field at index `i` of `FIELDS`.
+                pub const fn bits(self) -> #bits_ty {
+                    self.0
+                }
+
+                /// Whether no fields are in the set.
+                pub const fn is_empty(self) -> bool {
+                    self.0 == 0
+                }
+
+                /// Whether every field in `other` is in the set.
+                pub const fn contains(self, other: Self) -> bool {
+                    self.0 & other.0 == other.0
+                }
+
+                /// Add the fields in `other` to the set.
+                pub fn insert(&mut self, other: Self) {
+                    self.0 |= other.0;
+                }
+
+                /// Remove the fields in `other` from the set.
+                pub fn remove(&mut self, other: Self) {
+                    self.0 &= !other.0;
+                }
+            }
+
+            #[automatically_derived]
+            impl ::core::ops::BitOr for #set_ident {
+                type Output = Self;
+
+                fn bitor(self, other: Self) -> Self {
+                    Self(self.0 | other.0)
+                }
+            }
+
+            #[automatically_derived]
+            impl ::core::ops::BitAnd for #set_ident {
+                type Output = Self;
+
+                fn bitand(self, other: Self) -> Self {
+                    Self(self.0 & other.0)
+                }
+            }
+        })
+    }
+
+    /// The struct's generics, plus the `bounds` for the type of each emitted field.
+    fn generics_with_field_bounds(
+        &self,
+        bounds: impl Fn(&Type) -> Vec<WherePredicate>,
+    ) -> Generics {
+        let mut generics = self.generics.clone();
+        let where_clause = generics.make_where_clause();
+        for field in self.emitted_fields() {
+            where_clause.predicates.extend(bounds(&field.ty));
+        }
+        generics
+    }
+
+    /// Generates `changed_fields` in its own impl block, since it needs every emitted field's
+    /// type to implement `PartialEq`.
+    fn diff(&self) -> Option<TokenStream> {
+        if !self.diff {
+            return None;
+        }
+
+        let ident = &self.ident;
+        let fields = self.emitted_fields();
+        let names = fields.iter().map(|field| self.field_name(field));
+        let ours = fields
+            .iter()
+            .map(|field| self.field_value(quote!(self), field));
+        let theirs = fields
+            .iter()
+            .map(|field| self.field_value(quote!(other), field));
+        let generics =
+            self.generics_with_field_bounds(|ty| vec![parse_quote!(#ty: ::core::cmp::PartialEq)]);
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        Some(quote! {
+            #[automatically_derived]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                fn changed_fields(&self, other: &Self) -> ::std::vec::Vec<&'static str> {
+                    let mut changed = ::std::vec::Vec::new();
+                    #(
+                        if #ours != #theirs {
+                            changed.push(#names);
+                        }
+                    )*
+                    changed
+                }
+            }
+        })
+    }
+
+    /// Generates `field_values` in its own impl block, since it needs every emitted field's
+    /// type to be `Copy` and convertible into `values_as`. Each bound carries its field type's
+    /// span, so a field of another type is reported where it's declared.
+    fn field_values(&self) -> Option<TokenStream> {
+        let values_as = self.values_as.as_ref()?;
+        let ident = &self.ident;
+        let fields = self.emitted_fields();
+        let fields_len = fields.len();
+        let names = fields.iter().map(|field| self.field_name(field));
+        let values = fields
+            .iter()
+            .map(|field| self.field_value(quote!(self), field));
+        let generics = self.generics_with_field_bounds(|ty| {
+            let bound = quote_spanned! {ty.span()=>
+                #ty: ::core::marker::Copy + ::core::convert::Into<#values_as>
+            };
+            vec![parse_quote!(#bound)]
+        });
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        Some(quote! {
+            #[automatically_derived]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                fn field_values(&self) -> [(&'static str, #values_as); #fields_len] {
+                    [#((#names, ::core::convert::Into::into(#values))),*]
+                }
+            }
+        })
+    }
+
+    /// Generates `fields_with_type_ids` in its own impl block, since `TypeId::of` needs every
+    /// type with a row to be `'static`.
+    fn type_ids(&self) -> Option<TokenStream> {
+        if !*self.type_ids {
+            return None;
+        }
+
+        let ident = &self.ident;
+        let rows = self.metadata_rows();
+        let names = rows.iter().map(|(field, _)| self.field_name(field));
+        let types = rows.iter().map(|(field, _)| &field.ty).collect::<Vec<_>>();
+        let mut generics = self.generics.clone();
+        generics.make_where_clause().predicates.extend(
+            types
+                .iter()
+                .map(|ty| -> WherePredicate { parse_quote!(#ty: 'static) }),
+        );
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        Some(quote! {
+            #[automatically_derived]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                fn fields_with_type_ids() -> ::std::vec::Vec<(&'static str, ::core::any::TypeId)> {
+                    ::std::vec![#((#names, ::core::any::TypeId::of::<#types>())),*]
+                }
+            }
+        })
+    }
+
+    /// Generates the `sync_with` test, named after both types, e.g. `base_in_sync_with_raw`.
+    fn sync_test(&self) -> Option<TokenStream> {
+        let other = self.sync_with.as_ref()?;
+        let other_ident = &other.segments.last().expect("paths aren't empty").ident;
+        let test_ident = format_ident!(
+            "{}_in_sync_with_{}",
+            RenameRule::SnakeCase.apply_to_variant_builtin(&self.ident.unraw().to_string()),
+            RenameRule::SnakeCase.apply_to_variant_builtin(&other_ident.unraw().to_string()),
+            span = other_ident.span()
+        );
+        let mod_ident = self.sync_mod_ident();
+        let names = self.names_slice(&self.fields_to_emit());
+        let omit = &self.omit;
+        let other_name = quote!(#other).to_string().replace(' ', "");
+        let body = in_child_module(quote! {
+            const OMITTED: &[&str] = &[#(#omit),*];
+            let expected = #names
+                .iter()
+                .copied()
+                .filter(|name| !OMITTED.contains(name))
+                .collect::<::std::vec::Vec<_>>();
+            ::field_names::__private::assert_fields(
+                #other_name,
+                <#other>::all_field_names(),
+                &expected,
+            );
+        });
+
+        Some(quote! {
+            #[cfg(test)]
+            mod #mod_ident {
+                #[allow(unused_imports)]
+                use super::*;
+
+                #[test]
+                pub(super) fn #test_ident() {
+                    #body
+                }
+            }
+        })
+    }
+
+    /// The module holding the `sync_with` test, named after the struct so that each struct in
+    /// a module gets its own.
+    fn sync_mod_ident(&self) -> Ident {
+        format_ident!(
+            "__{}_sync_with",
+            RenameRule::SnakeCase.apply_to_variant_builtin(&self.ident.unraw().to_string())
+        )
+    }
+
+    /// Generates `Partial{Struct}`, with an optional borrowed value per emitted field, and
+    /// `from_pairs`, which fills one in from name-value pairs.
+    fn pairs_parser(&self) -> Option<TokenStream> {
+        if !*self.from_pairs {
+            return None;
+        }
+
+        let ident = &self.ident;
+        let vis = &self.vis;
+        let partial_ident = self.partial_ident();
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let fields = self.emitted_fields();
+        let idents = fields.iter().map(|field| &field.ident).collect::<Vec<_>>();
+        let names = fields.iter().map(|field| self.field_name(field));
+        let partial_doc = format!(
+            "The values of `{}`'s fields found by `{}::from_pairs`, by field.",
+            ident, ident
+        );
+
+        Some(quote! {
+            #[doc = #partial_doc]
+            #[derive(
+                ::core::fmt::Debug,
+                ::core::default::Default,
+                ::core::clone::Clone,
+                ::core::marker::Copy,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+            )]
+            #[allow(dead_code)]
+            #vis struct #partial_ident<'pairs> {
+                #(pub #idents: ::core::option::Option<&'pairs str>,)*
+            }
+
+            #[automatically_derived]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                fn from_pairs<'pairs>(
+                    pairs: impl ::core::iter::IntoIterator<Item = (&'pairs str, &'pairs str)>,
+                ) -> ::core::result::Result<#partial_ident<'pairs>, ::field_names::SetFieldError> {
+                    let mut partial = #partial_ident::default();
+                    for (name, value) in pairs {
+                        match name {
+                            #(#names => partial.#idents = ::core::option::Option::Some(value),)*
+                            _ => {
+                                return ::core::result::Result::Err(
+                                    ::field_names::SetFieldError::UnknownField(
+                                        ::std::string::ToString::to_string(name),
+                                    ),
+                                );
+                            }
+                        }
+                    }
+                    ::core::result::Result::Ok(partial)
+                }
+            }
+        })
+    }
+
+    /// Generates `set_field_from_str` in its own impl block, since it needs every emitted
+    /// field's type to implement `FromStr` and the rest of the inherent items don't.
+    fn setters(&self) -> Option<TokenStream> {
+        if !self.setters {
+            return None;
+        }
+
+        let ident = &self.ident;
+        let fields = self.emitted_fields();
+        let generics = self.generics_with_field_bounds(|ty| {
+            vec![
+                parse_quote!(#ty: ::core::str::FromStr),
+                parse_quote!(<#ty as ::core::str::FromStr>::Err: ::core::fmt::Display),
+            ]
+        });
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let arms = fields.iter().map(|field| {
+            let name = self.field_name(field);
+            let field_ident = &field.ident;
+            quote! {
+                #name => {
+                    self.#field_ident = ::core::str::FromStr::from_str(value).map_err(|err| {
+                        ::field_names::SetFieldError::InvalidValue {
+                            field: #name,
+                            message: ::std::string::ToString::to_string(&err),
+                        }
+                    })?;
+                    ::core::result::Result::Ok(())
+                }
+            }
+        });
+
+        Some(quote! {
+            #[automatically_derived]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                fn set_field_from_str(
+                    &mut self,
+                    name: &str,
+                    value: &str,
+                ) -> ::core::result::Result<(), ::field_names::SetFieldError> {
+                    match name {
+                        #(#arms)*
+                        _ => ::core::result::Result::Err(
+                            ::field_names::SetFieldError::UnknownField(
+                                ::std::string::ToString::to_string(name),
+                            ),
+                        ),
+                    }
+                }
+            }
+        })
+    }
+}
+
+impl ToTokens for Receiver {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let fields = self.fields_to_emit();
+        let str_ty = quote!(&'static str);
+        let (fields_len, fields_value) = self.names_array(&fields, &str_ty, |name| name);
+
+        let (array_ty, array_value) =
+            if let (Some(elem_ty), Some(elem_ctor)) = (&self.elem_type, &self.elem_ctor) {
+                let elem_ty = quote!(#elem_ty);
+                let (len, value) =
+                    self.names_array(&fields, &elem_ty, |name| quote!(#elem_ctor(#name)));
+                (quote!([#elem_ty; #len]), value)
+            } else if self.typed {
+                let elem_ty = quote!(::field_names::FieldName);
+                let (len, value) = self.names_array(
+                    &fields,
+                    &elem_ty,
+                    |name| quote!(::field_names::FieldName(#name)),
+                );
+                (quote!([#elem_ty; #len]), value)
+            } else {
+                (quote!([&'static str; #fields_len]), fields_value.clone())
+            };
+
+        let mut items = vec![match &self.wrap_in {
+            Some(wrapper) => quote! {
+                const FIELDS: #wrapper = #wrapper(#array_value);
+            },
+            None => quote! {
+                const FIELDS: #array_ty = #array_value;
+            },
+        }];
+
+        // `Cow` isn't `Copy`, so rather than a const that callers would have to clone out of,
+        // the wrapped names come from a function that builds a fresh array on each call.
+        if let Some(Wrapper::Cow) = self.wrapper {
+            items.push(quote! {
+                fn fields() -> [::std::borrow::Cow<'static, str>; #fields_len] {
+                    const NAMES: [&'static str; #fields_len] = #fields_value;
+                    NAMES.map(::std::borrow::Cow::Borrowed)
+                }
+            });
+        }
+
+        let all_field_names = self.names_slice(&fields);
+        let type_name = ident.unraw().to_string();
+        items.push(quote! {
+            const fn all_field_names() -> &'static [&'static str] {
+                #all_field_names
+            }
+        });
+
+        if self.with_type_name {
+            items.push(quote! {
+                const TYPE_NAME: &'static str = #type_name;
+            });
+        }
+
+        if self.with_non_exhaustive {
+            let non_exhaustive = is_non_exhaustive(&self.attrs);
+            items.push(quote! {
+                const NON_EXHAUSTIVE: bool = #non_exhaustive;
+            });
+        }
+
+        if self.with_source_file {
+            items.push(quote! {
+                const fn source_file() -> &'static str {
+                    ::core::file!()
+                }
+            });
+        }
+
+        if self.with_module_path {
+            // `module_path!` expands where the derive's output is placed, i.e. in the module
+            // that declares the struct.
+            items.push(quote! {
+                const fn type_path() -> &'static str {
+                    ::core::concat!(::core::module_path!(), "::", #type_name)
+                }
+            });
+        }
+
+        if self.rename_all.is_some() {
+            let (len, original) =
+                self.names_array(&self.original_fields_to_emit(), &str_ty, |name| name);
+            items.push(quote! {
+                const FIELDS_ORIGINAL: [&'static str; #len] = #original;
+            });
+        }
+
+        // A struct with no fields has no meaningful `NonZeroUsize` count, so the const is left
+        // out entirely rather than failing to compile. With `extends` the count isn't known
+        // until the base's fields are, so the check happens when the const is evaluated.
+        if !self.nested_names().is_empty() || !fields.is_empty() {
+            items.push(quote! {
+                const FIELD_COUNT_NZ: ::core::num::NonZeroUsize =
+                    match ::core::num::NonZeroUsize::new(#fields_len) {
+                        ::core::option::Option::Some(count) => count,
+                        ::core::option::Option::None => ::core::panic!("struct has no fields"),
+                    };
+            });
+        }
+
+        if self.bytes {
+            let elem_ty = quote!(&'static [u8]);
+            let (len, value) = self.names_array(&fields, &elem_ty, |name| quote!(#name.as_bytes()));
+            items.push(quote! {
+                const FIELDS_BYTES: [#elem_ty; #len] = #value;
+            });
+        }
+
+        if self.lowercase_const {
+            let lower = fields.iter().map(|name| name.to_ascii_lowercase());
+            items.push(quote! {
+                const FIELDS_LOWER: [&'static str; #fields_len] = [
+                    #(#lower),*
+                ];
+            });
+        }
+
+        if self.name_lens {
+            let lens = fields.iter().map(String::len);
+            items.push(quote! {
+                const FIELD_NAME_LENS: [usize; #fields_len] = [
+                    #(#lens),*
+                ];
+            });
+        }
+
+        for (rule, const_ident) in &self.also.0 {
+            // The extra names split words the same way as `rename_all`.
+            let rule = self.rename_all.map_or(RenameAll::new(*rule), |rename_all| {
+                rename_all.with_rule(*rule)
+            });
+            let names = self
+                .emitted_fields()
+                .into_iter()
+                .map(|field| self.field_name_with(field, Some(rule)));
+            items.push(quote! {
+                const #const_ident: [&'static str; #fields_len] = [#(#names),*];
+            });
+        }
+
+        if let Some(trait_path) = &self.only_impl {
+            // Method resolution tries `Probe<T>` as the receiver before autoref'ing to
+            // `&Probe<T>`, so `Implemented` wins whenever its bound holds and `Fallback` is
+            // only reached otherwise. Bounds on the struct's type parameters can't be proven
+            // inside the impl, so fields of those types take the fallback.
+            let (names, types): (Vec<_>, Vec<_>) = self
+                .emitted_fields()
+                .into_iter()
+                .map(|field| (self.field_name(field), &field.ty))
+                .unzip();
+            items.push(quote! {
+                fn fields_implementing() -> ::std::vec::Vec<&'static str> {
+                    struct Probe<T>(::core::marker::PhantomData<T>);
+
+                    trait Implemented {
+                        fn implements(&self) -> bool {
+                            true
+                        }
+                    }
+
+                    impl<T: #trait_path> Implemented for Probe<T> {}
+
+                    trait Fallback {
+                        fn implements(&self) -> bool {
+                            false
+                        }
+                    }
+
+                    impl<T> Fallback for &Probe<T> {}
+
+                    let mut names = ::std::vec::Vec::new();
+                    #(
+                        if (&Probe::<#types>(::core::marker::PhantomData)).implements() {
+                            names.push(#names);
+                        }
+                    )*
+                    names
+                }
+            });
+        }
+
+        if let Some(initials) = &self.initials {
+            let mode = match initials {
+                Override::Inherit => Initials::First,
+                Override::Explicit(mode) => *mode,
+            };
+            // As with `titleize`, `prefix` and `suffix` are left out, so they don't give every
+            // field the same initial.
+            let initials = self
+                .emitted_fields()
+                .into_iter()
+                .map(|field| mode.abbreviate(&field.name(self.rename_all)));
+            items.push(quote! {
+                const FIELD_INITIALS: [&'static str; #fields_len] = [#(#initials),*];
+            });
+        }
+
+        if self.hash {
+            let hash = names_hash(&fields);
+            items.push(quote! {
+                const FIELDS_HASH: u64 = #hash;
+            });
+        }
+
+        if self.csv {
+            let columns = 1..=fields.len();
+            items.push(quote! {
+                const CSV_COLUMNS: [(&'static str, usize); #fields_len] = [
+                    #((#fields, #columns)),*
+                ];
+            });
+        }
+
+        if self.with_span_info {
+            // Spanning the macros to each field's identifier makes them report where the field
+            // was declared, which stable proc macros can't otherwise read from a `Span`.
+            let rows = self.metadata_rows();
+            let rows_len = rows.len();
+            let locations = rows.into_iter().map(|(field, _)| {
+                let name = self.field_name(field);
+                let span = field
+                    .ident
+                    .as_ref()
+                    .expect("FieldNames only supports named fields")
+                    .span();
+                quote_spanned! {span=>
+                    ::field_names::FieldLocation {
+                        name: #name,
+                        file: ::core::file!(),
+                        line: ::core::line!(),
+                        column: ::core::column!(),
+                    }
+                }
+            });
+            items.push(quote! {
+                const FIELD_LOCATIONS: [::field_names::FieldLocation; #rows_len] = [
+                    #(#locations),*
+                ];
+            });
+        }
+
+        if self.with_deprecated {
+            let deprecated = self
+                .emitted_fields()
+                .into_iter()
+                .map(ReceiverField::is_deprecated);
+            items.push(quote! {
+                const FIELDS_DEPRECATED: [(&'static str, bool); #fields_len] = [
+                    #((#fields, #deprecated)),*
+                ];
+            });
+        }
+
+        if self.with_defaultable {
+            // Read independently of `from_serde`, which would also apply serde's renames and
+            // skips to the names.
+            let container_default = serde::has_default(&self.attrs);
+            let defaultable = self
+                .emitted_fields()
+                .into_iter()
+                .map(|field| container_default || SerdeField::from_attrs(&field.attrs).default);
+            items.push(quote! {
+                const FIELDS_DEFAULTABLE: [(&'static str, bool); #fields_len] = [
+                    #((#fields, #defaultable)),*
+                ];
+            });
+        }
+
+        if let Some(separator) = &self.joined {
+            let mut joined = fields.join(separator);
+            if let Some(wrap) = &self.wrap {
+                let mid = wrap
+                    .char_indices()
+                    .nth(wrap.chars().count() / 2)
+                    .map_or(0, |(i, _)| i);
+                let (open, close) = wrap.split_at(mid);
+                joined = format!("{}{}{}", open, joined, close);
+            }
+            items.push(quote! {
+                const FIELDS_JOINED: &'static str = #joined;
+            });
+        }
+
+        if self.missing {
+            let (names, values): (Vec<_>, Vec<_>) = self
+                .emitted_fields()
+                .into_iter()
+                .filter(|field| field.is_option())
+                .map(|field| {
+                    (
+                        self.field_name(field),
+                        self.field_value(quote!(self), field),
+                    )
+                })
+                .unzip();
+            items.push(quote! {
+                fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+                    let mut missing = ::std::vec::Vec::new();
+                    #(
+                        if #values.is_none() {
+                            missing.push(#names);
+                        }
+                    )*
+                    missing
+                }
+            });
+        }
+
+        let (renamed_len, renamed) = self.renamed_fields();
+        if renamed_len > 0 {
+            items.push(quote! {
+                const RENAMED_FIELDS: [(&'static str, &'static str); #renamed_len] = [
+                    #((#renamed)),*
+                ];
+            });
+        }
+
+        // Like `fields_in_group`, this is keyed off the fields rather than a container option.
+        let tagged = self
+            .emitted_fields()
+            .into_iter()
+            .filter(|field| field.tag)
+            .map(|field| self.field_name(field))
+            .collect::<Vec<_>>();
+        if !tagged.is_empty() {
+            let tagged_len = tagged.len();
+            items.push(quote! {
+                const TAGGED_FIELDS: [&'static str; #tagged_len] = [#(#tagged),*];
+            });
+        }
+
+        let settable = self
+            .emitted_fields()
+            .into_iter()
+            .filter(|field| field.settable)
+            .map(|field| self.field_name(field))
+            .collect::<Vec<_>>();
+        if !settable.is_empty() {
+            let settable_len = settable.len();
+            items.push(quote! {
+                const SETTABLE_FIELDS: [&'static str; #settable_len] = [#(#settable),*];
+            });
+        }
+
+        if self.has_groups() {
+            let (groups, names): (Vec<_>, Vec<_>) = self
+                .emitted_fields()
+                .into_iter()
+                .map(|field| {
+                    let group = field.group.as_deref().unwrap_or(DEFAULT_GROUP);
+                    (group, self.field_name(field))
+                })
+                .unzip();
+            items.push(quote! {
+                fn fields_in_group(group: &str) -> ::std::vec::Vec<&'static str> {
+                    let mut fields = ::std::vec::Vec::new();
+                    #(
+                        if group == #groups {
+                            fields.push(#names);
+                        }
+                    )*
+                    fields
+                }
+            });
+        }
+
+        if self.type_and_fields {
+            let names = self.names_slice(&fields);
+            items.push(quote! {
+                fn type_and_fields() -> (&'static str, &'static [&'static str]) {
+                    (::core::any::type_name::<Self>(), #names)
+                }
+            });
+        }
+
+        if self.titleize {
+            // Labels are built from the renamed name without `prefix` or `suffix`, which
+            // usually exist for machines rather than readers.
+            let labels = self
+                .emitted_fields()
+                .into_iter()
+                .map(|field| titleize(&field.name(self.rename_all)));
+            items.push(quote! {
+                const FIELD_LABELS: [&'static str; #fields_len] = [#(#labels),*];
+            });
+        }
+
+        if self.with_attrs {
+            let entries = self.emitted_fields().into_iter().map(|field| {
+                let name = self.field_name(field);
+                let attrs = field.attrs.iter().map(|attr| {
+                    let (path, tokens) = (&attr.path, &attr.tokens);
+                    quote!(#path #tokens).to_string()
+                });
+                quote!((#name, &[#(#attrs),*]))
+            });
+            items.push(quote! {
+                const FIELD_ATTRS: [(&'static str, &'static [&'static str]); #fields_len] = [
+                    #(#entries),*
+                ];
+            });
+        }
+
+        if *self.preserve_skipped_rows {
+            let rows = self.metadata_rows();
+            let rows_len = rows.len();
+            let skipped = rows.into_iter().map(|(_, skipped)| skipped);
+            items.push(quote! {
+                const FIELDS_SKIPPED: [bool; #rows_len] = [#(#skipped),*];
+            });
+        }
+
+        if self.lowercase_lookup {
+            let names = self.names_slice(&fields);
+            items.push(quote! {
+                fn field_index(name: &str) -> ::core::option::Option<usize> {
+                    let names: &'static [&'static str] = #names;
+                    names.iter().position(|field| {
+                        field
+                            .chars()
+                            .flat_map(char::to_lowercase)
+                            .eq(name.chars().flat_map(char::to_lowercase))
+                    })
+                }
+
+                fn contains_field(name: &str) -> bool {
+                    Self::field_index(name).is_some()
+                }
+            });
+        }
+
+        if cfg!(feature = "serde_json") {
+            let names = self.names_slice(&fields);
+            items.push(quote! {
+                fn fields_as_json_value() -> ::field_names::__private::serde_json::Value {
+                    ::field_names::__private::serde_json::Value::Array(
+                        #names
+                            .iter()
+                            .map(|name| ::field_names::__private::serde_json::Value::from(*name))
+                            .collect(),
+                    )
+                }
+            });
+        }
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #(#items)*
+            }
+        });
+
+        if self.validate && !self.nested_names().is_empty() {
+            let message = format!("`{}` has no fields to emit", ident);
+            tokens.extend(quote! {
+                const _: () = {
+                    if #fields_len == 0 {
+                        ::core::panic!(#message);
+                    }
+                };
+            });
+        }
+
+        if self.count_const {
+            let vis = &self.vis;
+            let count_ident = format_ident!(
+                "{}_FIELD_COUNT",
+                RenameRule::ScreamingSnakeCase.apply_to_variant_builtin(&ident.unraw().to_string()),
+                span = ident.span()
+            );
+            let doc = format!("The number of names in `{}::FIELDS`.", ident);
+            tokens.extend(quote! {
+                #[doc = #doc]
+                #[allow(dead_code)]
+                #vis const #count_ident: usize = #fields_len;
+            });
+        }
+
+        tokens.extend(self.field_enum());
+        tokens.extend(self.setters());
+        tokens.extend(self.diff());
+        tokens.extend(self.field_values());
+        tokens.extend(self.bitset());
+        tokens.extend(self.type_ids());
+        tokens.extend(self.sync_test());
+        tokens.extend(self.pairs_parser());
+
+        if let Some(trait_path) = self.trait_path() {
+            // The names never depend on the struct's type parameters, so the impl needs no
+            // bounds beyond the ones the struct itself declares; in particular, `T: FieldNames`
+            // is not required.
+            tokens.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #trait_path for #ident #ty_generics #where_clause {
+                    const FIELDS: &'static [&'static str] = &#fields_value;
+                }
+            });
+        }
+    }
+}
+
+/// Rewrites the paths in `tokens` that start with `self` or `super` to name the same items
+/// from a module nested in the one the tokens were written for.
+fn in_child_module(tokens: TokenStream) -> TokenStream {
+    let mut after_colons = false;
+    tokens
+        .into_iter()
+        .flat_map(|token| {
+            let starts_path = !after_colons;
+            after_colons = matches!(&token, TokenTree::Punct(punct) if punct.as_char() == ':');
+            match token {
+                TokenTree::Group(group) => {
+                    let mut rewritten =
+                        Group::new(group.delimiter(), in_child_module(group.stream()));
+                    rewritten.set_span(group.span());
+                    TokenTree::Group(rewritten).into_token_stream()
+                }
+                TokenTree::Ident(ident) if starts_path && ident == "self" => {
+                    Ident::new("super", ident.span()).into_token_stream()
+                }
+                TokenTree::Ident(ident) if starts_path && ident == "super" => {
+                    quote_spanned!(ident.span()=> super::super)
+                }
+                token => token.into_token_stream(),
+            }
+        })
+        .collect()
+}
+
+#[derive(FromField)]
+#[darling(attributes(field_names), forward_attrs, and_then = "Self::validate")]
+struct ReceiverField {
+    ident: Option<Ident>,
+    ty: Type,
+    /// Every attribute on the field other than `#[field_names(...)]`.
+    attrs: Vec<Attribute>,
+    #[darling(default)]
+    skip: bool,
+    #[darling(default)]
+    keep: bool,
+    #[darling(default)]
+    rename: Option<SpannedValue<Rename>>,
+    #[darling(default)]
+    group: Option<String>,
+    /// Include the field in `TAGGED_FIELDS`.
+    #[darling(default)]
+    tag: bool,
+    /// Include the field in `SETTABLE_FIELDS`.
+    #[darling(default)]
+    settable: bool,
+    /// Sort key for the field's position among the emitted fields, lowest first.
+    #[darling(default)]
+    priority: i32,
+    /// Filled in from the field's serde attributes when the struct uses `from_serde`.
+    #[darling(skip)]
+    serde: SerdeField,
+}
+
+/// The value of a field's `#[field_names(rename = "...")]`.
+struct Rename(String);
+
+impl FromMeta for Rename {
+    fn from_value(value: &Lit) -> darling::Result<Self> {
+        match value {
+            Lit::Str(name) => Ok(Rename(name.value())),
+            _ => Err(
+                Error::custom("`rename` expects a string, such as `rename = \"name\"`")
+                    .with_span(value),
+            ),
+        }
+    }
+}
+
+/// The group of fields without a `#[field_names(group = "...")]`.
+const DEFAULT_GROUP: &str = "default";
+
+impl ReceiverField {
+    fn validate(self) -> darling::Result<Self> {
+        if let (true, Some(rename)) = (self.skip, &self.rename) {
+            return Err(
+                Error::custom("`rename` has no effect on a skipped field").with_span(rename)
+            );
+        }
+
+        Ok(self)
+    }
+
+    /// The field's identifier, without the `r#` of a raw identifier, as serde writes it.
+    fn original_name(&self) -> String {
+        self.ident
+            .as_ref()
+            .expect("FieldNames only supports named fields")
+            .unraw()
+            .to_string()
+    }
+
+    /// The field's variant in the `field_enum` enum: the `PascalCase` form of its Rust name or,
+    /// when that isn't an identifier, as for `_1`, of its `rename`.
+    fn enum_variant_ident(&self) -> darling::Result<Ident> {
+        let ident = self
+            .ident
+            .as_ref()
+            .expect("FieldNames only supports named fields");
+        let pascal_ident = |name: &str| {
+            let name = RenameRule::PascalCase.apply_to_field_builtin(name);
+            syn::parse_str::<Ident>(&name)
+                .ok()
+                .map(|variant| Ident::new(&variant.to_string(), ident.span()))
+        };
+
+        pascal_ident(&ident.unraw().to_string())
+            .or_else(|| {
+                self.rename
+                    .as_ref()
+                    .and_then(|rename| pascal_ident(&rename.0))
+            })
+            .ok_or_else(|| {
+                Error::custom(format!(
+                    "`{}` has no `PascalCase` form to name its `field_enum` variant; \
+                     name it with `#[field_names(rename = \"...\")]`",
+                    ident
+                ))
+                .with_span(ident)
+            })
+    }
+
+    /// The field's identifier in `SCREAMING_SNAKE_CASE`, for naming generated consts.
+    fn const_ident(&self) -> Ident {
+        let ident = self
+            .ident
+            .as_ref()
+            .expect("FieldNames only supports named fields");
+        let name =
+            RenameRule::ScreamingSnakeCase.apply_to_field_builtin(&ident.unraw().to_string());
+        Ident::new(&name, ident.span())
+    }
+
+    fn is_named(&self, path: &Path) -> bool {
+        match &self.ident {
+            Some(ident) => path.is_ident(ident),
+            None => false,
+        }
+    }
+
+    /// Whether the field's type is a path ending in the `::`-separated segments of `suffix`,
+    /// ignoring generic arguments. `"String"` and `"string::String"` both match
+    /// `std::string::String`.
+    fn type_ends_with(&self, suffix: &str) -> bool {
+        let path = match &self.ty {
+            Type::Path(ty) if ty.qself.is_none() => &ty.path,
+            _ => return false,
+        };
+
+        let wanted = suffix.split("::").map(str::trim).collect::<Vec<_>>();
+        wanted.len() <= path.segments.len()
+            && path
+                .segments
+                .iter()
+                .rev()
+                .zip(wanted.iter().rev())
+                .all(|(segment, wanted)| segment.ident == wanted)
+    }
+
+    /// Whether the field's type is spelled `Option<T>`, with or without a leading path. Type
+    /// aliases for `Option` are not detected.
+    fn is_option(&self) -> bool {
+        let path = match &self.ty {
+            Type::Path(ty) if ty.qself.is_none() => &ty.path,
+            _ => return false,
+        };
+
+        match path.segments.last() {
+            Some(segment) if segment.ident == "Option" => matches!(
+                &segment.arguments,
+                PathArguments::AngleBracketed(args) if args.args.len() == 1
+            ),
+            _ => false,
+        }
+    }
+
+    fn is_deprecated(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|attr| attr.path.is_ident("deprecated"))
+    }
+
+    fn is_doc_hidden(&self) -> bool {
+        self.attrs.iter().any(|attr| {
+            attr.path.is_ident("doc")
+                && match attr.parse_meta() {
+                    Ok(Meta::List(list)) => list.nested.iter().any(|nested| {
+                        matches!(nested, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("hidden"))
+                    }),
+                    _ => false,
+                }
+        })
+    }
+
+    fn name(&self, rename_all: Option<RenameAll>) -> String {
+        if let Some(rename) = &self.rename {
+            return rename.0.clone();
+        }
+
+        if let Some(rename) = &self.serde.rename {
+            return rename.clone();
+        }
+
+        let original = self.original_name();
+        match rename_all {
+            Some(rule) => rule.apply_to_field(&original),
+            None => original,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{in_child_module, Receiver};
+    use darling::FromDeriveInput;
+    use quote::quote;
+    use syn::parse_quote;
+
+    #[test]
+    fn simple() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            struct Example {
+                hello: String,
+                world: String,
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            input.fields_to_emit(),
+            vec!["hello".to_string(), "world".to_string()]
+        );
+    }
+
+    #[test]
+    fn skip_field() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            struct Example {
+                hello: String,
+                #[field_names(skip)]
+                hidden: bool,
+                world: String,
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            input.fields_to_emit(),
+            vec!["hello".to_string(), "world".to_string()]
+        );
+    }
+
+    #[test]
+    fn skip_matching() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            #[field_names(skip_matching = "*_cache", skip_matching = "_internal*")]
+            struct Example {
+                hello: String,
+                lookup_cache: Vec<u8>,
+                world: String,
+                render_cache: Vec<u8>,
+                _internal_state: u8,
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            input.fields_to_emit(),
+            vec!["hello".to_string(), "world".to_string()]
+        );
+    }
+
+    #[test]
+    fn container_skip() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            #[field_names(skip(secret, cache))]
+            struct Example {
+                hello: String,
+                secret: String,
+                #[field_names(skip)]
+                hidden: bool,
+                world: String,
+                cache: Vec<u8>,
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            input.fields_to_emit(),
+            vec!["hello".to_string(), "world".to_string()]
+        );
+    }
+
+    #[test]
+    fn container_skip_unknown_field() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            #[field_names(skip(secret, missing))]
+            struct Example {
+                hello: String,
+                secret: String,
+            }
+        });
+
+        assert!(input.is_err());
+    }
+
+    #[test]
+    fn skip_doc_hidden() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            #[field_names(skip_doc_hidden)]
+            struct Example {
+                /// Shown.
+                hello: String,
+                #[doc(hidden)]
+                internal: bool,
+                #[doc = "Also shown."]
+                world: String,
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            input.fields_to_emit(),
+            vec!["hello".to_string(), "world".to_string()]
+        );
+    }
+
+    #[test]
+    fn only_type() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            #[field_names(only_type = "String", only_type = "collections::BTreeSet")]
+            struct Example {
+                name: String,
+                age: u8,
+                path: std::string::String,
+                tags: std::collections::BTreeSet<String>,
+                other: my::collections::Vec<String>,
+                maybe: Option<String>,
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            input.fields_to_emit(),
+            vec!["name".to_string(), "path".to_string(), "tags".to_string()]
+        );
+    }
+
+    #[test]
+    fn opt_in() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            #[field_names(opt_in)]
+            struct Example {
+                #[field_names(keep)]
+                hello: String,
+                internal: u8,
+                #[field_names(keep)]
+                world: String,
+                cache: Vec<u8>,
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            input.fields_to_emit(),
+            vec!["hello".to_string(), "world".to_string()]
+        );
+    }
+
+    #[test]
+    fn keep_requires_opt_in() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            struct Example {
+                #[field_names(keep)]
+                hello: String,
+            }
+        });
+
+        assert!(input.is_err());
+    }
+
+    #[test]
+    fn rename_field() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            #[field_names(rename_all = "camelCase")]
+            struct Example {
+                #[field_names(rename = "ID")]
+                id: u32,
+                minutes_to_midnight: u32,
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            input.fields_to_emit(),
+            vec!["ID".to_string(), "minutesToMidnight".to_string()]
+        );
+    }
+
+    #[test]
+    fn skip_rejects_rename() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            struct Example {
+                #[field_names(skip, rename = "x")]
+                hello: String,
+            }
+        });
+
+        assert!(input.is_err());
+    }
+
+    #[test]
+    fn from_serde() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            #[field_names(from_serde)]
+            #[serde(rename_all = "kebab-case")]
+            struct Example {
+                post_id: u64,
+                #[serde(rename(serialize = "ser", deserialize = "de"))]
+                renamed: String,
+                #[serde(skip)]
+                cache: Vec<u8>,
+                #[serde(flatten)]
+                extra: Extra,
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            input.fields_to_emit(),
+            vec!["post-id".to_string(), "ser".to_string()]
+        );
+        assert!(input.nested_names().is_empty());
+    }
+
+    #[test]
+    fn flatten_serde_requires_from_serde() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            #[field_names(flatten_serde = "recurse")]
+            struct Example {
+                hello: String,
+            }
+        });
+
+        assert!(input.is_err());
+    }
+
+    #[test]
+    fn rename_precedence() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            #[field_names(from_serde, rename_all = "SCREAMING_SNAKE_CASE", prefix = "x-", suffix = "!")]
+            #[serde(rename_all = "camelCase")]
+            struct Example {
+                plain_field: u8,
+                #[serde(rename = "serdeName")]
+                serde_renamed: u8,
+                #[field_names(rename = "own_name")]
+                #[serde(rename = "ignored")]
+                both_renamed: u8,
+                #[field_names(rename = "never")]
+                #[serde(skip)]
+                serde_skipped: u8,
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            input.fields_to_emit(),
+            vec![
+                "x-PLAIN_FIELD!".to_string(),
+                "x-serdeName!".to_string(),
+                "x-own_name!".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn serde_rename_all_is_fallback() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            #[field_names(from_serde)]
+            #[serde(rename_all = "camelCase")]
+            struct Example {
+                plain_field: u8,
+            }
+        })
+        .unwrap();
+
+        assert_eq!(input.fields_to_emit(), vec!["plainField".to_string()]);
+    }
+
+    #[test]
+    fn serde_ignored_without_from_serde() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            #[field_names(prefix = "p_")]
+            #[serde(rename_all = "camelCase")]
+            struct Example {
+                plain_field: u8,
+                #[serde(rename = "other")]
+                renamed: u8,
+                #[serde(skip)]
+                skipped: u8,
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            input.fields_to_emit(),
+            vec![
+                "p_plain_field".to_string(),
+                "p_renamed".to_string(),
+                "p_skipped".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn rename_all() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            #[field_names(rename_all = "camelCase")]
+            struct Example {
+                hello: String,
+                minutes_to_midnight: u32,
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            input.fields_to_emit(),
+            vec!["hello".to_string(), "minutesToMidnight".to_string()]
+        );
+        assert_eq!(
+            input.original_fields_to_emit(),
+            vec!["hello".to_string(), "minutes_to_midnight".to_string()]
+        );
+    }
+
+    #[test]
+    fn extends_rejects_joined() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            #[field_names(extends = "Base", joined = ", ")]
+            struct Example {
+                hello: String,
+            }
+        });
+
+        assert!(input.is_err());
+    }
+
+    #[test]
+    fn recurse_rejects_generic_field_type() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            #[field_names(from_serde, flatten_serde = "recurse")]
+            struct Example<'a, T> {
+                hello: String,
+                #[serde(flatten)]
+                inner: Inner<'a, T>,
+            }
+        });
+
+        assert!(input.is_err());
+    }
+
+    #[test]
+    fn recurse_allows_concrete_field_type() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            #[field_names(from_serde, flatten_serde = "recurse")]
+            struct Example<T> {
+                hello: T,
+                #[serde(flatten)]
+                inner: Inner<u8>,
+            }
+        });
+
+        assert!(input.is_ok());
+    }
+
+    #[test]
+    fn field_enum_variants() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            #[field_names(field_enum, rename_all = "kebab-case")]
+            struct Example {
+                minutes_to_midnight: u32,
+                r#type: String,
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            input
+                .emitted_fields()
+                .iter()
+                .map(|field| field.enum_variant_ident().unwrap().to_string())
+                .collect::<Vec<_>>(),
+            vec!["MinutesToMidnight".to_string(), "Type".to_string()]
+        );
+    }
+
+    #[test]
+    fn field_enum_variant_from_rename() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            #[field_names(field_enum)]
+            struct Example {
+                #[field_names(rename = "first_column")]
+                _1: u8,
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            input.emitted_fields()[0]
+                .enum_variant_ident()
+                .unwrap()
+                .to_string(),
+            "FirstColumn"
+        );
+    }
+
+    #[test]
+    fn split_field_attributes_merge() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            struct Example {
+                #[field_names(rename = "id")]
+                #[serde(default)]
+                #[field_names(tag)]
+                user_id: u64,
+            }
+        })
+        .unwrap();
+
+        let fields = input.emitted_fields();
+        assert_eq!(input.field_name(fields[0]), "id");
+        assert!(fields[0].tag);
+    }
+
+    #[test]
+    fn also_rejects_bad_consts() {
+        for attr in [
+            quote!(#[field_names(also(title_case = "FIELDS_TITLE"))]),
+            quote!(#[field_names(also(camelCase = "FIELDS"))]),
+            quote!(#[field_names(also(camelCase = "NAMES", kebab_case = "NAMES"))]),
+            quote!(#[field_names(with_source_file, also(snake_case = "source_file"))]),
+            quote!(#[field_names(also(camelCase = "FIELD_COUNT_NZ"))]),
+            quote!(#[field_names(rename_all = "camelCase", also(snake_case = "FIELDS_ORIGINAL"))]),
+            quote!(#[field_names(titleize, also(camelCase = "FIELD_LABELS"))]),
+        ] {
+            let input = Receiver::from_derive_input(&parse_quote! {
+                #[derive(FieldNames)]
+                #attr
+                struct Example {
+                    user_id: u64,
+                }
+            });
+
+            assert!(input.is_err(), "{}", attr);
+        }
+    }
+
+    #[test]
+    fn also_allows_names_of_unset_options() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            #[field_names(also(camelCase = "FIELD_LABELS", kebab_case = "FIELDS_LOWER"))]
+            struct Example {
+                user_id: u64,
+            }
+        });
+
+        assert!(input.is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "from_pairs")]
+    fn from_pairs_requires_emitted_fields() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            #[field_names(from_pairs)]
+            struct Example {
+                #[field_names(skip)]
+                hello: String,
+            }
+        });
+
+        assert!(input.is_err());
+    }
+
+    #[test]
+    #[cfg(not(feature = "from_pairs"))]
+    fn from_pairs_requires_feature() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(FieldNames)]
+            #[field_names(from_pairs)]
+            struct Example {
+                hello: String,
+            }
+        });
+
+        assert!(input.is_err());
+    }
+
+    #[test]
+    fn child_module_paths() {
+        assert_eq!(
+            in_child_module(quote!(
+                <super::Raw as self::Named>::NAMES,
+                crate::super_fields::X
+            ))
+            .to_string(),
+            quote!(
+                <super::super::Raw as super::Named>::NAMES,
+                crate::super_fields::X
+            )
+            .to_string()
+        );
+    }
+}
@@ -0,0 +1,43 @@
+/// Matches `name` against `pattern`, where each `*` in the pattern stands for any run of
+/// characters, including none. Every other character must match exactly.
+pub(crate) fn glob_matches(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    if !name.starts_with(first) {
+        return false;
+    }
+
+    let mut rest = &name[first.len()..];
+    let parts = parts.collect::<Vec<_>>();
+    let (last, middle) = match parts.split_last() {
+        Some(split) => split,
+        // No wildcard at all, so the whole name must have been consumed by the prefix.
+        None => return rest.is_empty(),
+    };
+
+    for part in middle {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+
+    rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::glob_matches;
+
+    #[test]
+    fn wildcards() {
+        assert!(glob_matches("hello", "hello"));
+        assert!(!glob_matches("hello", "hello_world"));
+        assert!(glob_matches("_internal*", "_internal_state"));
+        assert!(glob_matches("*_cache", "lookup_cache"));
+        assert!(!glob_matches("*_cache", "cache"));
+        assert!(glob_matches("a*b*c", "a_b_c"));
+        assert!(!glob_matches("a*b*c", "a_c_b"));
+        assert!(glob_matches("*", "anything"));
+    }
+}
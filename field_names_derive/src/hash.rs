@@ -0,0 +1,29 @@
+/// A 64-bit FNV-1a hash of `names`, for the `FIELDS_HASH` and `VARIANTS_HASH` consts.
+///
+/// Each name is followed by a `0xff` byte, which never occurs in UTF-8, so moving characters
+/// between adjacent names always changes the input. The hash only depends on the names and
+/// their order, so it's the same across builds, platforms and compiler versions.
+pub(crate) fn names_hash<S: AsRef<str>>(names: &[S]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    names
+        .iter()
+        .flat_map(|name| name.as_ref().bytes().chain(Some(0xff)))
+        .fold(OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::names_hash;
+
+    #[test]
+    fn fnv1a() {
+        let empty: [&str; 0] = [];
+        assert_eq!(names_hash(&empty), 0xcbf2_9ce4_8422_2325);
+        assert_ne!(names_hash(&["ab", "c"]), names_hash(&["a", "bc"]));
+        assert_ne!(names_hash(&["a", "b"]), names_hash(&["b", "a"]));
+    }
+}
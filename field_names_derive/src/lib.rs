@@ -0,0 +1,59 @@
+extern crate proc_macro;
+
+use darling::FromDeriveInput;
+use quote::quote;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Item};
+
+mod casing;
+mod fields;
+mod glob;
+mod hash;
+mod serde;
+mod variants;
+
+/// Whether the item is marked `#[non_exhaustive]`, for the `NON_EXHAUSTIVE` const that both
+/// derives emit under `with_non_exhaustive`.
+fn is_non_exhaustive(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .any(|attr| attr.path.is_ident("non_exhaustive"))
+}
+
+#[proc_macro_derive(FieldNames, attributes(field_names))]
+pub fn derive_field_names(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    fields::Receiver::from_derive_input(&parse_macro_input!(input as DeriveInput))
+        .map(|receiver| quote!(#receiver))
+        .unwrap_or_else(|err| err.write_errors())
+        .into()
+}
+
+#[proc_macro_derive(VariantNames, attributes(variant_names))]
+pub fn derive_variant_names(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    variants::Receiver::from_derive_input(&parse_macro_input!(input as DeriveInput))
+        .map(|receiver| quote!(#receiver))
+        .unwrap_or_else(|err| err.write_errors())
+        .into()
+}
+
+/// Derives `FieldNames` for structs or `VariantNames` for enums, reading the same attributes
+/// as the derive it stands in for.
+#[proc_macro_derive(Names, attributes(field_names, variant_names))]
+pub fn derive_names(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let expanded = match &input.data {
+        Data::Enum(_) => variants::Receiver::from_derive_input(&input).map(|r| quote!(#r)),
+        Data::Struct(_) | Data::Union(_) => {
+            fields::Receiver::from_derive_input(&input).map(|r| quote!(#r))
+        }
+    };
+
+    expanded.unwrap_or_else(|err| err.write_errors()).into()
+}
+
+#[proc_macro_attribute]
+pub fn fields(
+    args: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    fields::expand_attribute(args.into(), parse_macro_input!(input as Item)).into()
+}
@@ -0,0 +1,94 @@
+use darling::FromMeta;
+use syn::{Attribute, Lit, Meta, NestedMeta};
+
+use crate::casing::RenameRule;
+
+/// The parts of a field's, or variant's, `#[serde(...)]` attributes that affect its wire name.
+#[derive(Debug, Default)]
+pub(crate) struct SerdeField {
+    pub rename: Option<String>,
+    pub skip: bool,
+    pub flatten: bool,
+    pub default: bool,
+}
+
+impl SerdeField {
+    pub fn from_attrs(attrs: &[Attribute]) -> Self {
+        let mut field = Self::default();
+        for meta in serde_metas(attrs) {
+            if meta.path().is_ident("rename") {
+                field.rename = serialize_name(&meta);
+            } else if meta.path().is_ident("default") {
+                field.default = true;
+            } else if let Meta::Path(path) = &meta {
+                if path.is_ident("skip") || path.is_ident("skip_serializing") {
+                    field.skip = true;
+                } else if path.is_ident("flatten") {
+                    field.flatten = true;
+                }
+            }
+        }
+        field
+    }
+}
+
+/// Whether the container has `#[serde(default)]` or `#[serde(default = "...")]`, which lets
+/// every field be missing when deserializing.
+pub(crate) fn has_default(attrs: &[Attribute]) -> bool {
+    serde_metas(attrs).any(|meta| meta.path().is_ident("default"))
+}
+
+/// The container's `#[serde(rename_all = "...")]` rule for serialization, if any.
+pub(crate) fn rename_all(attrs: &[Attribute]) -> Option<RenameRule> {
+    rename_rule(attrs, "rename_all")
+}
+
+/// An enum's `#[serde(rename_all_fields = "...")]` rule for the fields of its struct variants.
+pub(crate) fn rename_all_fields(attrs: &[Attribute]) -> Option<RenameRule> {
+    rename_rule(attrs, "rename_all_fields")
+}
+
+fn rename_rule(attrs: &[Attribute], key: &str) -> Option<RenameRule> {
+    serde_metas(attrs)
+        .filter(|meta| meta.path().is_ident(key))
+        .find_map(|meta| serialize_name(&meta))
+        .and_then(|rule| RenameRule::from_string(&rule).ok())
+}
+
+/// The items of every `#[serde(...)]` attribute. Malformed attributes are passed over, since
+/// serde reports those itself.
+fn serde_metas(attrs: &[Attribute]) -> impl Iterator<Item = Meta> + '_ {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("serde"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|nested| match nested {
+            NestedMeta::Meta(meta) => Some(meta),
+            NestedMeta::Lit(_) => None,
+        })
+}
+
+/// The value of `key = "..."`, or the `serialize` value of `key(serialize = "...")`.
+fn serialize_name(meta: &Meta) -> Option<String> {
+    match meta {
+        Meta::NameValue(value) => lit_str(&value.lit),
+        Meta::List(list) => list.nested.iter().find_map(|nested| match nested {
+            NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("serialize") => {
+                lit_str(&value.lit)
+            }
+            _ => None,
+        }),
+        Meta::Path(_) => None,
+    }
+}
+
+fn lit_str(lit: &Lit) -> Option<String> {
+    match lit {
+        Lit::Str(value) => Some(value.value()),
+        _ => None,
+    }
+}
@@ -0,0 +1,1092 @@
+use darling::{
+    ast::{Data, Fields, Style},
+    util::Override,
+    Error, FromDeriveInput, FromField, FromMeta, FromVariant,
+};
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{
+    ext::IdentExt, spanned::Spanned, Attribute, Expr, Generics, Ident, Path, Type, UnOp, Visibility,
+};
+
+use crate::{
+    casing::{titleize, RenameAll, RenameRule},
+    glob::glob_matches,
+    hash::names_hash,
+    is_non_exhaustive,
+    serde::{self, SerdeField},
+};
+
+#[derive(FromDeriveInput)]
+#[darling(
+    attributes(variant_names),
+    supports(enum_any),
+    forward_attrs(non_exhaustive, serde),
+    and_then = "Self::validate"
+)]
+pub(crate) struct Receiver {
+    ident: Ident,
+    vis: Visibility,
+    generics: Generics,
+    data: Data<ReceiverVariant, ()>,
+    attrs: Vec<Attribute>,
+    /// Emit `VARIANT_FIELDS`, pairing each variant name with the names of its fields.
+    #[darling(default)]
+    with_fields: bool,
+    /// Emit `VARIANTS_ARITY`, pairing each variant name with its number of fields.
+    #[darling(default)]
+    with_arity: bool,
+    /// Emit `VARIANT_CTORS`, pairing each variant name with a function that constructs it.
+    /// Only unit variants can be constructed this way.
+    #[darling(default)]
+    with_ctors: bool,
+    /// Emit a `{VARIANT}_FIELDS` const for each variant, holding the names of its fields.
+    #[darling(default)]
+    field_consts: bool,
+    /// Emit `VARIANTS_TAGGED`, pairing each variant name with an integer tag.
+    #[darling(default)]
+    tagged: Option<Override<TaggedOptions>>,
+    /// Skip variants whose names match any of these globs.
+    #[darling(multiple)]
+    skip_matching: Vec<String>,
+    /// Convert variant names with the same casing rules, and results, as serde.
+    #[darling(default)]
+    rename_all: Option<RenameAll>,
+    /// Read `rename`, `skip`, `rename_all` and `rename_all_fields` from `#[serde(...)]`
+    /// attributes on the enum, its variants and their fields.
+    #[darling(default)]
+    from_serde: bool,
+    /// Generate a fieldless `Copy` enum with one variant per emitted variant, named
+    /// `{Enum}Kind` unless a name is given, and emit `VARIANT_KINDS` listing its values.
+    #[darling(default)]
+    kind_enum: Option<Override<Ident>>,
+    /// Emit `SCHEMA`, describing each variant's name, style, arity and field names.
+    #[darling(default)]
+    schema: bool,
+    /// Emit `VARIANT_LABELS`, the names as human-readable words, e.g. `Send Request`.
+    #[darling(default)]
+    titleize: bool,
+    /// Implement the trait at this path, setting its `&'static [&'static str]` const to the
+    /// variant names.
+    #[darling(default)]
+    impl_trait: Option<Path>,
+    /// The name of the `impl_trait` const, `VARIANTS` by default.
+    #[darling(default)]
+    const_name: Option<Ident>,
+    /// The path of the `field_names` crate in generated code, for when it's re-exported or
+    /// renamed.
+    #[darling(default, rename = "crate")]
+    krate: Option<Path>,
+    /// Emit `VARIANTS_HASH`, a stable hash of the emitted names, for detecting enum drift.
+    #[darling(default)]
+    hash: bool,
+    /// Emit `TYPE_NAME`, the enum's declared identifier.
+    #[darling(default)]
+    with_type_name: bool,
+    /// Emit `NON_EXHAUSTIVE`, whether the enum is marked `#[non_exhaustive]`.
+    #[darling(default)]
+    with_non_exhaustive: bool,
+    /// Generate `source_file`, the `file!()` of the file that defines the enum.
+    #[darling(default)]
+    with_source_file: bool,
+    /// Emit `DEFAULT_VARIANT`, the name of the `#[default]` variant, if any.
+    #[darling(default)]
+    with_default_variant: bool,
+    /// Emit `NON_DEFAULT_VARIANTS`, the names other than the `#[default]` variant's.
+    #[darling(default)]
+    with_non_default_variants: bool,
+    /// Generate `name_from_discriminant`, which finds a variant's name by its discriminant.
+    #[darling(default)]
+    with_discriminant_lookup: bool,
+}
+
+/// Options for `#[variant_names(tagged(...))]`.
+#[derive(Debug, Default, FromMeta)]
+struct TaggedOptions {
+    /// Let skipped variants use up a tag, so each tag is the variant's declaration index.
+    #[darling(default)]
+    count_skipped: bool,
+}
+
+impl Receiver {
+    fn validate(mut self) -> darling::Result<Self> {
+        if self.from_serde {
+            if self.rename_all.is_none() {
+                self.rename_all = serde::rename_all(&self.attrs).map(RenameAll::new);
+            }
+
+            let rename_all_fields = serde::rename_all_fields(&self.attrs);
+            if let Data::Enum(variants) = &mut self.data {
+                for variant in variants {
+                    variant.serde = SerdeField::from_attrs(&variant.attrs);
+                    variant.field_rule = serde::rename_all(&variant.attrs).or(rename_all_fields);
+                    for field in &mut variant.fields.fields {
+                        field.serde = SerdeField::from_attrs(&field.attrs);
+                    }
+                }
+            }
+        }
+
+        if self.with_ctors {
+            let errors = self
+                .emitted_variants()
+                .into_iter()
+                .filter(|v| !v.fields.is_unit())
+                .map(|v| {
+                    Error::custom("`with_ctors` only supports unit variants").with_span(&v.ident)
+                })
+                .collect::<Vec<_>>();
+            if !errors.is_empty() {
+                return Err(Error::multiple(errors));
+            }
+        }
+
+        if let Some(const_name) = &self.const_name {
+            if self.impl_trait.is_none() {
+                return Err(
+                    Error::custom("`const_name` requires `impl_trait`").with_span(const_name)
+                );
+            }
+        }
+
+        if let Some(kind_ident) = self.kind_enum_ident() {
+            if kind_ident == self.ident {
+                return Err(Error::custom(format!(
+                    "`kind_enum` would generate a type named `{}`, which is already the enum's name",
+                    kind_ident
+                ))
+                .with_span(&kind_ident));
+            }
+        }
+
+        if self.tagged.is_some() {
+            let errors = self
+                .all_variants()
+                .into_iter()
+                .filter_map(|v| match &v.discriminant {
+                    Some(Expr::Unary(unary)) if matches!(unary.op, UnOp::Neg(_)) => Some(unary),
+                    _ => None,
+                })
+                .map(|negative| {
+                    Error::custom(
+                        "`tagged` needs non-negative discriminants, since tags are `usize`",
+                    )
+                    .with_span(negative)
+                })
+                .collect::<Vec<_>>();
+            if !errors.is_empty() {
+                return Err(Error::multiple(errors));
+            }
+        }
+
+        if self.field_consts {
+            self.check_field_const_names()?;
+        }
+
+        Ok(self)
+    }
+
+    /// The associated consts emitted other than by `field_consts`, with the option that emits
+    /// each, or `None` for those that are always emitted.
+    fn generated_consts(&self) -> Vec<(&'static str, Option<&'static str>)> {
+        let mut consts = vec![("VARIANTS", None)];
+        let optional = [
+            ("VARIANT_FIELDS", "with_fields", self.with_fields),
+            ("VARIANTS_ARITY", "with_arity", self.with_arity),
+            ("VARIANTS_TAGGED", "tagged", self.tagged.is_some()),
+            ("VARIANT_CTORS", "with_ctors", self.with_ctors),
+            ("VARIANT_LABELS", "titleize", self.titleize),
+            ("SCHEMA", "schema", self.schema),
+            ("VARIANT_KINDS", "kind_enum", self.kind_enum.is_some()),
+            ("VARIANTS_HASH", "hash", self.hash),
+            ("TYPE_NAME", "with_type_name", self.with_type_name),
+            (
+                "NON_EXHAUSTIVE",
+                "with_non_exhaustive",
+                self.with_non_exhaustive,
+            ),
+            (
+                "DEFAULT_VARIANT",
+                "with_default_variant",
+                self.with_default_variant,
+            ),
+            (
+                "NON_DEFAULT_VARIANTS",
+                "with_non_default_variants",
+                self.with_non_default_variants,
+            ),
+            ("TAGGED_VARIANTS", "tag", !self.tagged_variants().is_empty()),
+        ];
+        consts.extend(
+            optional
+                .iter()
+                .filter(|(_, _, set)| *set)
+                .map(|(name, option, _)| (*name, Some(*option))),
+        );
+        consts
+    }
+
+    /// Rejects `field_consts` names that another generated const, or another variant, already
+    /// uses, e.g. `VARIANT_FIELDS` for a variant named `Variant` alongside `with_fields`.
+    fn check_field_const_names(&self) -> darling::Result<()> {
+        let generated = self.generated_consts();
+        let variants = self.emitted_variants();
+        let mut errors = Vec::new();
+        for (i, variant) in variants.iter().enumerate() {
+            let const_ident = variant.fields_const_ident();
+            let clash =
+                if let Some((_, option)) = generated.iter().find(|(name, _)| const_ident == name) {
+                    Some(match option {
+                        Some(option) => format!("emitted by `{}`", option),
+                        None => "emitted for every enum".to_string(),
+                    })
+                } else {
+                    variants[..i]
+                        .iter()
+                        .find(|other| other.fields_const_ident() == const_ident)
+                        .map(|other| format!("emitted for `{}`", other.ident))
+                };
+            if let Some(clash) = clash {
+                errors.push(
+                    Error::custom(format!(
+                        "`field_consts` would emit `{}` for this variant, which is already {}",
+                        const_ident, clash
+                    ))
+                    .with_span(&variant.ident),
+                );
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::multiple(errors))
+        }
+    }
+
+    /// The path generated code uses for the `field_names` crate.
+    fn crate_path(&self) -> TokenStream {
+        match &self.krate {
+            Some(path) => quote!(#path),
+            None => quote!(::field_names),
+        }
+    }
+
+    fn all_variants(&self) -> Vec<&ReceiverVariant> {
+        self.data
+            .as_ref()
+            .take_enum()
+            .expect("VariantNames only takes enums")
+    }
+
+    fn is_emitted(&self, variant: &ReceiverVariant) -> bool {
+        let name = variant.ident.unraw().to_string();
+        !variant.skip
+            && !variant.serde.skip
+            && !self
+                .skip_matching
+                .iter()
+                .any(|pattern| glob_matches(pattern, &name))
+    }
+
+    fn emitted_variants(&self) -> Vec<&ReceiverVariant> {
+        self.all_variants()
+            .into_iter()
+            .filter(|v| self.is_emitted(v))
+            .collect()
+    }
+
+    /// The tag of each emitted variant for `VARIANTS_TAGGED`. If any variant has an explicit
+    /// discriminant, tags follow Rust's discriminant rules, counting skipped variants, so each
+    /// tag is the variant's discriminant. Otherwise tags count up from 0, by default skipping
+    /// the skipped variants.
+    fn variant_tags(&self) -> Vec<TokenStream> {
+        if self.has_discriminants() {
+            return self.discriminants(quote!(usize));
+        }
+
+        let count_skipped = match &self.tagged {
+            Some(Override::Explicit(options)) => options.count_skipped,
+            _ => false,
+        };
+
+        let mut tags = Vec::new();
+        let mut tag = 0usize;
+        for variant in self.all_variants() {
+            let emitted = self.is_emitted(variant);
+            if emitted {
+                tags.push(quote!(#tag));
+            }
+            if emitted || count_skipped {
+                tag += 1;
+            }
+        }
+
+        tags
+    }
+
+    fn has_discriminants(&self) -> bool {
+        self.all_variants().iter().any(|v| v.discriminant.is_some())
+    }
+
+    /// Expressions for the discriminant of each emitted variant, cast to `ty`. A variant
+    /// without an explicit discriminant is one more than the variant before it, skipped or not.
+    fn discriminants(&self, ty: TokenStream) -> Vec<TokenStream> {
+        self.discriminant_parts()
+            .into_iter()
+            .map(|(base, offset)| {
+                let offset = Literal::usize_unsuffixed(offset);
+                match base {
+                    Some(base) => quote!((#base) as #ty + #offset),
+                    None => quote!(#offset),
+                }
+            })
+            .collect()
+    }
+
+    /// Compile-time checks that the tags taken from discriminants that aren't literals, such as
+    /// `BASE` or `1 << 4`, fit in `usize`. Negative literals are rejected in `validate`.
+    fn tag_checks(&self) -> TokenStream {
+        if self.tagged.is_none() {
+            return TokenStream::new();
+        }
+
+        self.discriminant_parts()
+            .into_iter()
+            .filter_map(|(base, offset)| match base {
+                Some(base) if !matches!(base, Expr::Lit(_)) => Some((base, offset)),
+                _ => None,
+            })
+            .map(|(base, offset)| {
+                let offset = Literal::usize_unsuffixed(offset);
+                quote_spanned! {base.span()=>
+                    const _: () = {
+                        let tag = (#base) as i128 + #offset;
+                        ::core::assert!(
+                            tag >= 0 && tag <= usize::MAX as i128,
+                            "`tagged` needs discriminants that fit in `usize`"
+                        );
+                    };
+                }
+            })
+            .collect()
+    }
+
+    /// The discriminant of each emitted variant, as the nearest explicit discriminant at or
+    /// before it, if any, and how many variants after that one it comes.
+    fn discriminant_parts(&self) -> Vec<(Option<&Expr>, usize)> {
+        let mut parts = Vec::new();
+        let mut base: Option<&Expr> = None;
+        let mut offset = 0usize;
+        for variant in self.all_variants() {
+            if let Some(discriminant) = &variant.discriminant {
+                base = Some(discriminant);
+                offset = 0;
+            }
+            if self.is_emitted(variant) {
+                parts.push((base, offset));
+            }
+            offset += 1;
+        }
+
+        parts
+    }
+
+    fn tagged_variants(&self) -> Vec<String> {
+        self.emitted_variants()
+            .into_iter()
+            .filter(|v| v.tag)
+            .map(|v| v.name(self.rename_all))
+            .collect()
+    }
+
+    fn variants_to_emit(&self) -> Vec<String> {
+        self.emitted_variants()
+            .into_iter()
+            .map(|v| v.name(self.rename_all))
+            .collect()
+    }
+
+    fn variant_fields_to_emit(&self) -> Vec<(String, Vec<String>)> {
+        self.emitted_variants()
+            .into_iter()
+            .map(|v| (v.name(self.rename_all), v.field_names()))
+            .collect()
+    }
+
+    fn variant_arities_to_emit(&self) -> Vec<(String, usize)> {
+        self.emitted_variants()
+            .into_iter()
+            .map(|v| (v.name(self.rename_all), v.fields.len()))
+            .collect()
+    }
+}
+
+impl Receiver {
+    fn kind_enum_ident(&self) -> Option<Ident> {
+        self.kind_enum.as_ref().map(|kind_enum| match kind_enum {
+            Override::Inherit => format_ident!("{}Kind", self.ident),
+            Override::Explicit(ident) => ident.clone(),
+        })
+    }
+
+    /// The enum generated by `kind_enum`, whose variants share the emitted variants'
+    /// identifiers while `as_str` and `Display` produce their emitted names.
+    fn kind_enum(&self) -> Option<TokenStream> {
+        let enum_ident = self.kind_enum_ident()?;
+        let vis = &self.vis;
+        let variants = self
+            .emitted_variants()
+            .into_iter()
+            .map(|v| &v.ident)
+            .collect::<Vec<_>>();
+        let names = self.variants_to_emit();
+        let enum_doc = format!("The variants of `{}`, without their data.", self.ident);
+        let variant_docs = names.iter().map(|name| format!("The `{}` variant.", name));
+
+        Some(quote! {
+            // The derives are spelled out in full so a caller's own `Debug` or `Hash` items
+            // can't shadow them, and unused variants are expected when only some are matched.
+            #[doc = #enum_doc]
+            #[derive(
+                ::core::fmt::Debug,
+                ::core::clone::Clone,
+                ::core::marker::Copy,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+                ::core::hash::Hash,
+            )]
+            #[allow(dead_code)]
+            #vis enum #enum_ident {
+                #(
+                    #[doc = #variant_docs]
+                    #variants
+                ),*
+            }
+
+            #[automatically_derived]
+            impl #enum_ident {
+                /// The emitted name of this variant.
+                pub fn as_str(&self) -> &'static str {
+                    match self {
+                        #(Self::#variants => #names),*
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl ::core::fmt::Display for #enum_ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.write_str(self.as_str())
+                }
+            }
+        })
+    }
+}
+
+impl ToTokens for Receiver {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let variants = self.variants_to_emit();
+        let variants_len = variants.len();
+
+        let krate = self.crate_path();
+        let type_name = ident.unraw().to_string();
+        let mut items = vec![quote! {
+            const VARIANTS: [&'static str; #variants_len] = [
+                #(#variants),*
+            ];
+        }];
+
+        if self.with_type_name {
+            items.push(quote! {
+                const TYPE_NAME: &'static str = #type_name;
+            });
+        }
+
+        if self.with_non_exhaustive {
+            let non_exhaustive = is_non_exhaustive(&self.attrs);
+            items.push(quote! {
+                const NON_EXHAUSTIVE: bool = #non_exhaustive;
+            });
+        }
+
+        if self.with_source_file {
+            items.push(quote! {
+                const fn source_file() -> &'static str {
+                    ::core::file!()
+                }
+            });
+        }
+
+        if self.with_default_variant {
+            let name = self
+                .emitted_variants()
+                .into_iter()
+                .find(|v| v.is_default())
+                .map(|v| v.name(self.rename_all));
+            let default_variant = match name {
+                Some(name) => quote!(::core::option::Option::Some(#name)),
+                None => quote!(::core::option::Option::None),
+            };
+            items.push(quote! {
+                const DEFAULT_VARIANT: ::core::option::Option<&'static str> = #default_variant;
+            });
+        }
+
+        if self.with_non_default_variants {
+            let non_default = self
+                .emitted_variants()
+                .into_iter()
+                .filter(|v| !v.is_default())
+                .map(|v| v.name(self.rename_all))
+                .collect::<Vec<_>>();
+            let non_default_len = non_default.len();
+            items.push(quote! {
+                const NON_DEFAULT_VARIANTS: [&'static str; #non_default_len] = [#(#non_default),*];
+            });
+        }
+
+        // Like `FieldNames`'s `TAGGED_FIELDS`, this is keyed off the variants rather than an
+        // option on the enum.
+        let tagged = self.tagged_variants();
+        if !tagged.is_empty() {
+            let tagged_len = tagged.len();
+            items.push(quote! {
+                const TAGGED_VARIANTS: [&'static str; #tagged_len] = [#(#tagged),*];
+            });
+        }
+
+        if self.with_fields {
+            let entries = self
+                .emitted_variants()
+                .into_iter()
+                .zip(self.variant_fields_to_emit())
+                .map(
+                    |(variant, (name, fields))| match variant.transparent_type() {
+                        Some(ty) => quote!((#name, <#ty as #krate::FieldNames>::FIELDS)),
+                        None => quote!((#name, &[#(#fields),*])),
+                    },
+                );
+
+            items.push(quote! {
+                const VARIANT_FIELDS: [(&'static str, &'static [&'static str]); #variants_len] = [
+                    #(#entries),*
+                ];
+            });
+        }
+
+        if self.with_arity {
+            let (names, arities): (Vec<_>, Vec<_>) =
+                self.variant_arities_to_emit().into_iter().unzip();
+
+            items.push(quote! {
+                const VARIANTS_ARITY: [(&'static str, usize); #variants_len] = [
+                    #((#names, #arities)),*
+                ];
+            });
+        }
+
+        if self.tagged.is_some() {
+            let tags = self.variant_tags();
+            items.push(quote! {
+                const VARIANTS_TAGGED: [(usize, &'static str); #variants_len] = [
+                    #((#tags, #variants)),*
+                ];
+            });
+        }
+
+        if self.with_discriminant_lookup {
+            let discriminants = self.discriminants(quote!(i64));
+            items.push(quote! {
+                fn name_from_discriminant(d: i64) -> ::core::option::Option<&'static str> {
+                    #(
+                        if d == #discriminants {
+                            return ::core::option::Option::Some(#variants);
+                        }
+                    )*
+                    ::core::option::Option::None
+                }
+            });
+        }
+
+        if self.field_consts {
+            items.extend(self.emitted_variants().into_iter().map(|variant| {
+                let const_ident = variant.fields_const_ident();
+                let value = variant.fields_slice(&krate);
+                quote! {
+                    const #const_ident: &'static [&'static str] = #value;
+                }
+            }));
+        }
+
+        if self.with_ctors {
+            let (names, idents): (Vec<_>, Vec<_>) = self
+                .emitted_variants()
+                .into_iter()
+                .map(|v| (v.name(self.rename_all), &v.ident))
+                .unzip();
+
+            items.push(quote! {
+                const VARIANT_CTORS: [(&'static str, fn() -> Self); #variants_len] = [
+                    #((#names, || Self::#idents)),*
+                ];
+            });
+        }
+
+        if self.hash {
+            let hash = names_hash(&variants);
+            items.push(quote! {
+                const VARIANTS_HASH: u64 = #hash;
+            });
+        }
+
+        if self.titleize {
+            let labels = variants.iter().map(|name| titleize(name));
+            items.push(quote! {
+                const VARIANT_LABELS: [&'static str; #variants_len] = [#(#labels),*];
+            });
+        }
+
+        if self.schema {
+            let entries = self.emitted_variants().into_iter().map(|variant| {
+                let name = variant.name(self.rename_all);
+                let style = match variant.fields.style {
+                    Style::Unit => quote!(Unit),
+                    Style::Tuple => quote!(Tuple),
+                    Style::Struct => quote!(Struct),
+                };
+                let arity = variant.fields.len();
+                let fields = variant.fields_slice(&krate);
+                quote! {
+                    #krate::VariantSchema {
+                        name: #name,
+                        style: #krate::VariantStyle::#style,
+                        arity: #arity,
+                        fields: #fields,
+                    }
+                }
+            });
+            items.push(quote! {
+                const SCHEMA: [#krate::VariantSchema; #variants_len] = [
+                    #(#entries),*
+                ];
+            });
+        }
+
+        if let Some(kind_ident) = self.kind_enum_ident() {
+            let kinds = self.emitted_variants().into_iter().map(|v| &v.ident);
+            items.push(quote! {
+                const VARIANT_KINDS: [#kind_ident; #variants_len] = [
+                    #(#kind_ident::#kinds),*
+                ];
+            });
+        }
+
+        tokens.extend(quote! {
+            #[automatically_derived]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #(#items)*
+            }
+        });
+
+        if let Some(trait_path) = &self.impl_trait {
+            let const_name = match &self.const_name {
+                Some(const_name) => const_name.clone(),
+                None => format_ident!("VARIANTS"),
+            };
+            tokens.extend(quote! {
+                #[automatically_derived]
+                impl #impl_generics #trait_path for #ident #ty_generics #where_clause {
+                    const #const_name: &'static [&'static str] = &[#(#variants),*];
+                }
+            });
+        }
+
+        tokens.extend(self.kind_enum());
+        tokens.extend(self.tag_checks());
+    }
+}
+
+#[derive(FromVariant)]
+#[darling(
+    attributes(variant_names),
+    forward_attrs(default, serde),
+    and_then = "Self::validate"
+)]
+struct ReceiverVariant {
+    ident: Ident,
+    discriminant: Option<Expr>,
+    fields: Fields<ReceiverVariantField>,
+    attrs: Vec<Attribute>,
+    #[darling(default)]
+    skip: bool,
+    /// Report the fields of the variant's single field type in `VARIANT_FIELDS`.
+    #[darling(default)]
+    transparent: bool,
+    /// Include the variant in `TAGGED_VARIANTS`.
+    #[darling(default)]
+    tag: bool,
+    /// The variant's serde attributes, filled in by the enum under `from_serde`.
+    #[darling(skip)]
+    serde: SerdeField,
+    /// The casing rule for the variant's named fields, filled in by the enum under
+    /// `from_serde`.
+    #[darling(skip)]
+    field_rule: Option<RenameRule>,
+}
+
+impl ReceiverVariant {
+    fn validate(self) -> darling::Result<Self> {
+        if self.transparent && !(self.fields.is_tuple() && self.fields.len() == 1) {
+            return Err(Error::custom(
+                "`transparent` requires a tuple variant with exactly one field",
+            )
+            .with_span(&self.ident));
+        }
+
+        Ok(self)
+    }
+
+    /// The name of the variant's `field_consts` const, e.g. `HTTP_STATUS_FIELDS` for
+    /// `HttpStatus`. This ignores `rename_all`, since it names a Rust item.
+    fn fields_const_ident(&self) -> Ident {
+        let name = RenameRule::ScreamingSnakeCase
+            .apply_to_variant_builtin(&self.ident.unraw().to_string());
+        format_ident!("{}_FIELDS", name, span = self.ident.span())
+    }
+
+    /// Whether the variant is marked `#[default]` for `#[derive(Default)]`.
+    fn is_default(&self) -> bool {
+        self.attrs.iter().any(|attr| attr.path.is_ident("default"))
+    }
+
+    /// The type whose `FieldNames::FIELDS` stand in for this variant's own fields.
+    fn transparent_type(&self) -> Option<&Type> {
+        if self.transparent {
+            self.fields.fields.first().map(|field| &field.ty)
+        } else {
+            None
+        }
+    }
+
+    fn name(&self, rename_all: Option<RenameAll>) -> String {
+        if let Some(rename) = &self.serde.rename {
+            return rename.clone();
+        }
+
+        let original = self.ident.unraw().to_string();
+        match rename_all {
+            Some(rule) => rule.apply_to_variant(&original),
+            None => original,
+        }
+    }
+
+    /// The `&'static [&'static str]` of the variant's field names, or of its transparent type's.
+    fn fields_slice(&self, krate: &TokenStream) -> TokenStream {
+        match self.transparent_type() {
+            Some(ty) => quote!(<#ty as #krate::FieldNames>::FIELDS),
+            None => {
+                let fields = self.field_names();
+                quote!(&[#(#fields),*])
+            }
+        }
+    }
+
+    /// Names of the variant's non-skipped fields. Tuple fields are named by their position in
+    /// the variant, so skipping a field doesn't renumber the ones after it.
+    fn field_names(&self) -> Vec<String> {
+        self.fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| !field.skip && !field.serde.skip)
+            .map(|(index, field)| field.name(index, self.field_rule))
+            .collect()
+    }
+}
+
+#[derive(FromField)]
+#[darling(attributes(variant_names), forward_attrs(serde))]
+struct ReceiverVariantField {
+    ident: Option<Ident>,
+    ty: Type,
+    attrs: Vec<Attribute>,
+    #[darling(default)]
+    rename: Option<String>,
+    #[darling(default)]
+    skip: bool,
+    /// The field's serde attributes, filled in by the enum under `from_serde`.
+    #[darling(skip)]
+    serde: SerdeField,
+}
+
+impl ReceiverVariantField {
+    /// The field's name: its own `rename`, its `#[serde(rename)]`, or `rule` applied to its
+    /// identifier. Tuple fields are named by `index`, which no rule applies to.
+    fn name(&self, index: usize, rule: Option<RenameRule>) -> String {
+        if let Some(rename) = self.rename.as_ref().or(self.serde.rename.as_ref()) {
+            return rename.clone();
+        }
+
+        match (&self.ident, rule) {
+            (Some(ident), Some(rule)) => rule.apply_to_field_builtin(&ident.unraw().to_string()),
+            (Some(ident), None) => ident.unraw().to_string(),
+            (None, _) => index.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Receiver;
+    use darling::FromDeriveInput;
+    use syn::parse_quote;
+
+    #[test]
+    fn simple() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(VariantNames)]
+            enum Example {
+                Hello(String),
+                World {
+                    planet: String,
+                    person: String,
+                }
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            input.variants_to_emit(),
+            vec!["Hello".to_string(), "World".to_string()]
+        );
+    }
+
+    #[test]
+    fn skip_variant() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(VariantNames)]
+            enum Example {
+                Hello(String),
+                #[variant_names(skip)]
+                Secret(String),
+                World {
+                    planet: String,
+                    person: String,
+                },
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            input.variants_to_emit(),
+            vec!["Hello".to_string(), "World".to_string()]
+        );
+    }
+
+    #[test]
+    fn skip_matching() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(VariantNames)]
+            #[variant_names(skip_matching = "Internal*")]
+            enum Example {
+                Hello,
+                InternalRetry,
+                World,
+                InternalShutdown(u8),
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            input.variants_to_emit(),
+            vec!["Hello".to_string(), "World".to_string()]
+        );
+    }
+
+    #[test]
+    fn rename_all_matches_serde() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(VariantNames)]
+            #[variant_names(rename_all = "kebab-case")]
+            enum Example {
+                Hello,
+                SendRequest(String),
+                HttpStatusCode { code: u16 },
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            input.variants_to_emit(),
+            vec![
+                "hello".to_string(),
+                "send-request".to_string(),
+                "http-status-code".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn variant_fields() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(VariantNames)]
+            #[variant_names(with_fields)]
+            enum Example {
+                Unit,
+                Tuple(String, u8),
+                World {
+                    planet: String,
+                    #[variant_names(skip)]
+                    secret: bool,
+                    #[variant_names(rename = "human")]
+                    person: String,
+                },
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            input.variant_fields_to_emit(),
+            vec![
+                ("Unit".to_string(), vec![]),
+                ("Tuple".to_string(), vec!["0".to_string(), "1".to_string()]),
+                (
+                    "World".to_string(),
+                    vec!["planet".to_string(), "human".to_string()]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn from_serde() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(VariantNames)]
+            #[variant_names(with_fields, from_serde)]
+            #[serde(rename_all = "snake_case", rename_all_fields = "camelCase")]
+            enum Example {
+                #[serde(skip)]
+                Hidden,
+                Tuple(String, u8),
+                SendRequest {
+                    request_id: u64,
+                    #[serde(skip_serializing)]
+                    secret: bool,
+                    #[serde(rename = "who")]
+                    sent_by: String,
+                },
+                #[serde(rename = "status", rename_all = "SCREAMING_SNAKE_CASE")]
+                HttpStatus { status_code: u16 },
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            input.variant_fields_to_emit(),
+            vec![
+                ("tuple".to_string(), vec!["0".to_string(), "1".to_string()]),
+                (
+                    "send_request".to_string(),
+                    vec!["requestId".to_string(), "who".to_string()]
+                ),
+                ("status".to_string(), vec!["STATUS_CODE".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn serde_ignored_without_from_serde() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(VariantNames)]
+            #[variant_names(with_fields)]
+            #[serde(rename_all_fields = "camelCase")]
+            enum Example {
+                #[serde(skip)]
+                Hidden,
+                World { planet_name: String },
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            input.variant_fields_to_emit(),
+            vec![
+                ("Hidden".to_string(), vec![]),
+                ("World".to_string(), vec!["planet_name".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn variant_arities() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(VariantNames)]
+            #[variant_names(with_arity)]
+            enum Example {
+                Unit,
+                Tuple(String, u8),
+                World {
+                    planet: String,
+                    #[variant_names(skip)]
+                    secret: bool,
+                    person: String,
+                },
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            input.variant_arities_to_emit(),
+            vec![
+                ("Unit".to_string(), 0),
+                ("Tuple".to_string(), 2),
+                ("World".to_string(), 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn transparent_requires_newtype_variant() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(VariantNames)]
+            #[variant_names(with_fields)]
+            enum Example {
+                #[variant_names(transparent)]
+                Pair(String, u8),
+            }
+        });
+
+        assert!(input.is_err());
+    }
+
+    #[test]
+    fn ctors_require_unit_variants() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(VariantNames)]
+            #[variant_names(with_ctors)]
+            enum Example {
+                Hello,
+                World(String),
+                #[variant_names(skip)]
+                Hidden { value: u8 },
+            }
+        });
+
+        assert!(input.is_err());
+    }
+
+    #[test]
+    fn const_name_requires_impl_trait() {
+        let input = Receiver::from_derive_input(&parse_quote! {
+            #[derive(VariantNames)]
+            #[variant_names(const_name = "VALUES")]
+            enum Example {
+                Hello,
+            }
+        });
+
+        assert!(input.is_err());
+    }
+}
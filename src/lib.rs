@@ -1,24 +1,305 @@
-extern crate proc_macro;
+use std::{error::Error, fmt, ops::Deref};
 
-use darling::FromDeriveInput;
-use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+pub use field_names_derive::{fields, FieldNames, Names, VariantNames};
 
-mod fields;
-mod variants;
+/// A type whose field names are known at compile time.
+///
+/// `#[derive(FieldNames)]` implements this trait when the struct opts in with
+/// `#[field_names(impl_trait)]`. The generated impl only carries the struct's own generic
+/// bounds, so a `Wrapper<T>` implements `FieldNames` for every `T`.
+pub trait FieldNames {
+    /// The names of the type's fields that aren't skipped. They're in declaration order, after
+    /// any names from `extends`, unless the derive was given `reverse`, `first`, or a field's
+    /// `priority`, which reorder them.
+    const FIELDS: &'static [&'static str];
+}
+
+/// An object-safe view of [`FieldNames`], for use through `&dyn FieldNamesDyn`.
+///
+/// `FieldNames` itself can't be made into a trait object because `FIELDS` is an associated
+/// const. Every `FieldNames` type implements this trait automatically.
+pub trait FieldNamesDyn {
+    /// The names of the value's fields, in the same order as [`FieldNames::FIELDS`].
+    fn field_names_dyn(&self) -> &'static [&'static str];
+}
+
+impl<T: FieldNames + ?Sized> FieldNamesDyn for T {
+    fn field_names_dyn(&self) -> &'static [&'static str] {
+        T::FIELDS
+    }
+}
+
+/// A field name emitted by `#[field_names(typed)]`.
+///
+/// Wrapping the name keeps field names from being mixed up with arbitrary strings, while
+/// `Deref<Target = str>` and `PartialEq<&str>` keep it easy to compare and print.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FieldName(pub &'static str);
+
+impl Deref for FieldName {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl fmt::Display for FieldName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl PartialEq<&str> for FieldName {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<FieldName> for &str {
+    fn eq(&self, other: &FieldName) -> bool {
+        *self == other.0
+    }
+}
+
+/// Where a field was declared, emitted by `#[field_names(with_span_info)]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FieldLocation {
+    /// The field's emitted name.
+    pub name: &'static str,
+    /// The source file, as reported by `file!()`.
+    pub file: &'static str,
+    /// The 1-based line of the field's name.
+    pub line: u32,
+    /// The 1-based column of the field's name.
+    pub column: u32,
+}
+
+/// The structure of an enum variant, emitted in `SCHEMA` by `#[variant_names(schema)]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VariantSchema {
+    /// The variant's emitted name.
+    pub name: &'static str,
+    /// Whether the variant is a unit, tuple, or struct variant.
+    pub style: VariantStyle,
+    /// The number of fields the variant declares, including skipped ones.
+    pub arity: usize,
+    /// The names of the variant's fields, as in `VARIANT_FIELDS`.
+    pub fields: &'static [&'static str],
+}
+
+/// The kind of fields a variant has.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VariantStyle {
+    /// A variant with no fields, like `None`.
+    Unit,
+    /// A variant with positional fields, like `Some(T)`.
+    Tuple,
+    /// A variant with named fields, like `Move { x: i32, y: i32 }`.
+    Struct,
+}
+
+/// The error returned by `set_field_from_str`, generated by `#[field_names(setters)]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SetFieldError {
+    /// No emitted field has this name.
+    UnknownField(String),
+    /// The value couldn't be parsed into the field's type.
+    InvalidValue {
+        /// The name of the field being set.
+        field: &'static str,
+        /// The `Display` output of the type's `FromStr` error.
+        message: String,
+    },
+}
+
+impl fmt::Display for SetFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SetFieldError::UnknownField(name) => write!(f, "unknown field `{}`", name),
+            SetFieldError::InvalidValue { field, message } => {
+                write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+        }
+    }
+}
+
+impl Error for SetFieldError {}
+
+/// Panics unless `T` has exactly `expected` field names.
+///
+/// This guards against fields being added to or removed from `T` without the test being
+/// updated, without having to hard-code the full list of names.
+#[track_caller]
+pub fn assert_field_count<T: FieldNames>(expected: usize) {
+    let actual = T::FIELDS.len();
+    if actual != expected {
+        panic!(
+            "expected {} fields, found {}: {:?}",
+            expected,
+            actual,
+            T::FIELDS
+        );
+    }
+}
+
+/// Maps each of `A`'s field names to the index of the same name in `B`'s `FIELDS`, or `None`
+/// if `B` has no such field.
+///
+/// When every entry is `Some` and no index repeats, `A`'s fields are a permutation of some of
+/// `B`'s, which is what a view with reordered fields needs to stay in sync with its source.
+///
+/// ```
+/// # use field_names::{index_map, FieldNames};
+/// #[derive(FieldNames)]
+/// #[field_names(impl_trait)]
+/// struct Base {
+///     target: String,
+///     lorem: String,
+/// }
+///
+/// #[derive(FieldNames)]
+/// #[field_names(impl_trait)]
+/// struct View<'a> {
+///     lorem: &'a str,
+///     extra: &'a str,
+/// }
+///
+/// assert_eq!(index_map::<View, Base>(), [Some(1), None]);
+/// ```
+pub fn index_map<A: FieldNames + ?Sized, B: FieldNames + ?Sized>() -> Vec<Option<usize>> {
+    A::FIELDS
+        .iter()
+        .map(|name| B::FIELDS.iter().position(|other| other == name))
+        .collect()
+}
 
-#[proc_macro_derive(FieldNames, attributes(field_names))]
-pub fn derive_field_names(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    fields::Receiver::from_derive_input(&parse_macro_input!(input as DeriveInput))
-        .map(|receiver| quote!(#receiver))
-        .unwrap_or_else(|err| err.write_errors())
-        .into()
+/// Fails compilation unless two types have the same `FIELDS`, in the same order.
+///
+/// Both types must have an inherent `FIELDS` array of `&str`, which `#[derive(FieldNames)]`
+/// emits by default. The macro expands to an anonymous `const` item, so it works both at item
+/// scope and inside a function body, such as a test; either way the check runs at compile time.
+///
+/// ```
+/// # use field_names::{assert_same_fields, FieldNames};
+/// #[derive(FieldNames)]
+/// struct Base {
+///     target: String,
+///     lorem: String,
+/// }
+///
+/// #[derive(FieldNames)]
+/// struct View<'a> {
+///     target: &'a str,
+///     lorem: &'a str,
+/// }
+///
+/// assert_same_fields!(Base, View);
+///
+/// fn main() {
+///     assert_same_fields!(View, Base);
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_same_fields {
+    ($left:ty, $right:ty $(,)?) => {
+        const _: () = {
+            if !$crate::__private::fields_eq(&<$left>::FIELDS, &<$right>::FIELDS) {
+                ::core::panic!(::core::concat!(
+                    "`",
+                    ::core::stringify!($left),
+                    "` and `",
+                    ::core::stringify!($right),
+                    "` do not have the same fields"
+                ));
+            }
+        };
+    };
 }
 
-#[proc_macro_derive(VariantNames, attributes(variant_names))]
-pub fn derive_variant_names(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    variants::Receiver::from_derive_input(&parse_macro_input!(input as DeriveInput))
-        .map(|receiver| quote!(#receiver))
-        .unwrap_or_else(|err| err.write_errors())
-        .into()
+/// Panics unless a type's field names are exactly the listed names, in order.
+///
+/// The type must derive `FieldNames`; any options may be set, since the comparison uses the
+/// generated `all_field_names()`. On a mismatch, the panic message lists both sets of names
+/// along with the ones missing from each side.
+///
+/// ```
+/// # use field_names::{assert_fields, FieldNames};
+/// #[derive(FieldNames)]
+/// struct Base {
+///     target: String,
+///     lorem: String,
+/// }
+///
+/// assert_fields!(Base, ["target", "lorem"]);
+/// ```
+#[macro_export]
+macro_rules! assert_fields {
+    ($ty:ty, [$($name:expr),* $(,)?] $(,)?) => {
+        $crate::__private::assert_fields(
+            ::core::stringify!($ty),
+            <$ty>::all_field_names(),
+            &[$($name),*],
+        )
+    };
+}
+
+/// Support code for the crate's macros. Not public API.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "serde_json")]
+    pub use serde_json;
+
+    #[track_caller]
+    pub fn assert_fields(type_name: &str, actual: &[&str], expected: &[&str]) {
+        if actual == expected {
+            return;
+        }
+
+        let missing = expected
+            .iter()
+            .filter(|name| !actual.contains(name))
+            .collect::<Vec<_>>();
+        let unexpected = actual
+            .iter()
+            .filter(|name| !expected.contains(name))
+            .collect::<Vec<_>>();
+        panic!(
+            "`{}` does not have the expected fields\n  expected: {:?}\n     found: {:?}\n   missing: {:?}\nunexpected: {:?}",
+            type_name, expected, actual, missing, unexpected
+        );
+    }
+
+    pub const fn fields_eq(left: &[&str], right: &[&str]) -> bool {
+        if left.len() != right.len() {
+            return false;
+        }
+
+        let mut i = 0;
+        while i < left.len() {
+            if !str_eq(left[i], right[i]) {
+                return false;
+            }
+            i += 1;
+        }
+
+        true
+    }
+
+    const fn str_eq(left: &str, right: &str) -> bool {
+        let (left, right) = (left.as_bytes(), right.as_bytes());
+        if left.len() != right.len() {
+            return false;
+        }
+
+        let mut i = 0;
+        while i < left.len() {
+            if left[i] != right[i] {
+                return false;
+            }
+            i += 1;
+        }
+
+        true
+    }
 }
@@ -0,0 +1,39 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[allow(dead_code)]
+struct Plain {
+    hello: String,
+    world: String,
+}
+
+#[derive(FieldNames)]
+#[field_names(typed, rename_all = "camelCase")]
+#[allow(dead_code)]
+struct Typed {
+    first_name: String,
+}
+
+#[derive(FieldNames)]
+#[field_names(impl_trait)]
+#[allow(dead_code)]
+struct Base {
+    id: u64,
+}
+
+#[derive(FieldNames)]
+#[field_names(extends = "Base")]
+#[allow(dead_code)]
+struct Extended {
+    name: String,
+}
+
+const PLAIN: &[&str] = Plain::all_field_names();
+
+#[test]
+fn matches_fields() {
+    assert_eq!(PLAIN, Plain::FIELDS);
+    assert_eq!(Typed::all_field_names(), ["firstName"]);
+    assert_eq!(Typed::all_field_names(), Typed::FIELDS);
+    assert_eq!(Extended::all_field_names(), Extended::FIELDS);
+}
@@ -0,0 +1,41 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(also(camelCase = "FIELDS_CAMEL", kebab_case = "FIELDS_KEBAB"))]
+#[allow(dead_code)]
+struct Example {
+    user_id: u64,
+    #[field_names(skip)]
+    secret: String,
+    display_name: String,
+    #[field_names(rename = "ID")]
+    id: u64,
+}
+
+#[test]
+fn native_names_unchanged() {
+    assert_eq!(Example::FIELDS, ["user_id", "display_name", "ID"]);
+}
+
+#[test]
+fn extra_casings() {
+    assert_eq!(Example::FIELDS_CAMEL, ["userId", "displayName", "ID"]);
+    assert_eq!(Example::FIELDS_KEBAB, ["user-id", "display-name", "ID"]);
+}
+
+#[derive(FieldNames)]
+#[field_names(
+    rename_all = "camelCase",
+    also(snake_case = "FIELDS_SNAKE", SCREAMING_KEBAB_CASE = "HEADERS")
+)]
+#[allow(dead_code)]
+struct Renamed {
+    user_id: u64,
+}
+
+#[test]
+fn alongside_rename_all() {
+    assert_eq!(Renamed::FIELDS, ["userId"]);
+    assert_eq!(Renamed::FIELDS_SNAKE, ["user_id"]);
+    assert_eq!(Renamed::HEADERS, ["USER-ID"]);
+}
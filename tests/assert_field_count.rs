@@ -0,0 +1,22 @@
+use field_names::{assert_field_count, FieldNames};
+
+#[derive(FieldNames)]
+#[field_names(impl_trait)]
+#[allow(dead_code)]
+struct Example {
+    hello: String,
+    world: String,
+    #[field_names(skip)]
+    hidden: bool,
+}
+
+#[test]
+fn matching_count() {
+    assert_field_count::<Example>(2);
+}
+
+#[test]
+#[should_panic(expected = "expected 3 fields, found 2")]
+fn mismatched_count() {
+    assert_field_count::<Example>(3);
+}
@@ -0,0 +1,36 @@
+use field_names::{assert_fields, FieldNames};
+
+#[derive(FieldNames)]
+#[allow(dead_code)]
+struct Base {
+    target: String,
+    lorem: String,
+    #[field_names(skip)]
+    secret: bool,
+}
+
+#[derive(FieldNames)]
+#[field_names(typed)]
+#[allow(dead_code)]
+struct Typed {
+    target: String,
+}
+
+#[test]
+fn matching() {
+    assert_fields!(Base, ["target", "lorem"]);
+    assert_fields!(Base, ["target", "lorem",]);
+    assert_fields!(Typed, ["target"]);
+}
+
+#[test]
+#[should_panic(expected = "missing: [\"dest\"]\nunexpected: [\"target\"]")]
+fn mismatched() {
+    assert_fields!(Base, ["dest", "lorem"]);
+}
+
+#[test]
+#[should_panic(expected = "`Base` does not have the expected fields")]
+fn misordered() {
+    assert_fields!(Base, ["lorem", "target"]);
+}
@@ -0,0 +1,29 @@
+use field_names::{assert_same_fields, FieldNames};
+
+#[derive(FieldNames)]
+#[allow(dead_code)]
+struct Base {
+    target: String,
+    lorem: String,
+    #[field_names(skip)]
+    secret: bool,
+}
+
+#[derive(FieldNames)]
+#[allow(dead_code)]
+struct View<'a> {
+    target: &'a str,
+    lorem: &'a str,
+}
+
+assert_same_fields!(Base, View);
+
+#[test]
+fn same_fields_compiles() {
+    assert_eq!(Base::FIELDS, View::FIELDS);
+}
+
+#[test]
+fn inside_function_body() {
+    assert_same_fields!(View, Base);
+}
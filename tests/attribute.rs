@@ -0,0 +1,30 @@
+#[field_names::fields(rename_all = "camelCase")]
+#[allow(dead_code)]
+struct Example {
+    first_name: String,
+    last_name: String,
+    #[field_names(skip)]
+    hidden: (),
+}
+
+#[field_names::fields]
+#[allow(dead_code)]
+struct NoArgs {
+    hello: String,
+}
+
+#[field_names::fields]
+#[field_names(rename_all = "camelCase")]
+#[allow(dead_code)]
+struct SeparateOptions {
+    first_name: String,
+    #[field_names(skip)]
+    hidden: (),
+}
+
+#[test]
+fn attribute() {
+    assert_eq!(Example::FIELDS, ["firstName", "lastName"]);
+    assert_eq!(NoArgs::FIELDS, ["hello"]);
+    assert_eq!(SeparateOptions::FIELDS, ["firstName"]);
+}
@@ -0,0 +1,30 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(bytes)]
+#[allow(dead_code)]
+struct Example {
+    hello: String,
+    world: String,
+}
+
+#[derive(FieldNames)]
+#[field_names(impl_trait)]
+#[allow(dead_code)]
+struct Base {
+    id: u64,
+}
+
+#[derive(FieldNames)]
+#[field_names(bytes, extends = "Base")]
+#[allow(dead_code)]
+struct Extended {
+    name: String,
+}
+
+#[test]
+fn bytes() {
+    assert_eq!(Example::FIELDS_BYTES[0], Example::FIELDS[0].as_bytes());
+    assert_eq!(Example::FIELDS_BYTES, [&b"hello"[..], &b"world"[..]]);
+    assert_eq!(Extended::FIELDS_BYTES, [&b"id"[..], &b"name"[..]]);
+}
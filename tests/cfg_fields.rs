@@ -0,0 +1,57 @@
+//! `cfg` attributes are evaluated before derives run, so fields configured out never reach
+//! the derive and `FIELDS` always matches the fields that are compiled in.
+
+use field_names::{FieldNames, VariantNames};
+
+#[derive(FieldNames)]
+#[field_names(name_lens, count_const)]
+#[allow(dead_code)]
+struct Gated {
+    hello: String,
+    #[cfg(feature = "serde_json")]
+    extra: u8,
+    #[cfg(not(feature = "serde_json"))]
+    fallback: u8,
+    #[cfg(any())]
+    never: u8,
+    #[cfg_attr(feature = "serde_json", field_names(skip))]
+    sometimes_skipped: u8,
+    world: String,
+}
+
+#[derive(VariantNames)]
+#[variant_names(with_fields)]
+#[allow(dead_code)]
+enum GatedVariants {
+    Always,
+    #[cfg(feature = "serde_json")]
+    Extra {
+        #[cfg(any())]
+        never: u8,
+        value: u8,
+    },
+}
+
+#[test]
+#[cfg(feature = "serde_json")]
+fn feature_enabled() {
+    assert_eq!(Gated::FIELDS, ["hello", "extra", "world"]);
+    assert_eq!(GATED_FIELD_COUNT, 3);
+    assert_eq!(Gated::FIELD_NAME_LENS, [5, 5, 5]);
+    assert_eq!(
+        GatedVariants::VARIANT_FIELDS,
+        [("Always", &[][..]), ("Extra", &["value"][..])]
+    );
+}
+
+#[test]
+#[cfg(not(feature = "serde_json"))]
+fn feature_disabled() {
+    assert_eq!(
+        Gated::FIELDS,
+        ["hello", "fallback", "sometimes_skipped", "world"]
+    );
+    assert_eq!(GATED_FIELD_COUNT, 4);
+    assert_eq!(Gated::FIELD_NAME_LENS, [5, 8, 17, 5]);
+    assert_eq!(GatedVariants::VARIANT_FIELDS, [("Always", &[][..])]);
+}
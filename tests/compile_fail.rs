@@ -0,0 +1,8 @@
+//! Checks that misuse of the crate is reported at compile time, with the errors pinned in
+//! `tests/compile_fail/*.stderr`. Regenerate those files with `TRYBUILD=overwrite`.
+
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile_fail/*.rs");
+}
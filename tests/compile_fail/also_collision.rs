@@ -0,0 +1,21 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(lowercase_const, also(lowercase = "FIELDS_LOWER"))]
+struct Example {
+    hello_world: String,
+}
+
+#[derive(FieldNames)]
+#[field_names(with_type_name, also(camelCase = "TYPE_NAME"))]
+struct TypeName {
+    hello_world: String,
+}
+
+#[derive(FieldNames)]
+#[field_names(rename_all = "camelCase", also(snake_case = "RENAMED_FIELDS"))]
+struct Renamed {
+    hello_world: String,
+}
+
+fn main() {}
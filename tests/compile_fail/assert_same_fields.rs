@@ -0,0 +1,17 @@
+use field_names::{assert_same_fields, FieldNames};
+
+#[derive(FieldNames)]
+struct Base {
+    target: String,
+    lorem: String,
+}
+
+#[derive(FieldNames)]
+struct Raw {
+    dest: String,
+    lorem: String,
+}
+
+assert_same_fields!(Base, Raw);
+
+fn main() {}
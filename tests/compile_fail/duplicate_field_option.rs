@@ -0,0 +1,12 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+struct Example {
+    #[field_names(rename = "a")]
+    #[field_names(rename = "b")]
+    hello: String,
+    #[field_names(skip, skip)]
+    hidden: bool,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(impl_trait)]
+struct Base<T> {
+    value: T,
+}
+
+#[derive(FieldNames)]
+#[field_names(extends = "Base<T>")]
+struct Example<T> {
+    other: T,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use field_names::VariantNames;
+
+#[derive(VariantNames)]
+#[variant_names(field_consts, with_fields)]
+enum Shape {
+    Variant { sides: u8 },
+    Circle { radius: f64 },
+}
+
+fn main() {}
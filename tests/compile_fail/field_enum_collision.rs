@@ -0,0 +1,10 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(bitset, field_enum = "ExampleFields")]
+struct Example {
+    hello: String,
+    world: u8,
+}
+
+fn main() {}
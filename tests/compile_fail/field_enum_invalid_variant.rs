@@ -0,0 +1,11 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(field_enum)]
+struct Example {
+    hello: String,
+    _1: u8,
+    __: u8,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+struct Example {
+    hello: String,
+}
+
+#[field_names::fields]
+impl Example {}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(first(secret, missing))]
+struct Example {
+    hello: String,
+    #[field_names(skip)]
+    secret: bool,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(layout_order)]
+#[repr(align(8))]
+struct Header {
+    magic: u32,
+    flags: u8,
+}
+
+fn main() {}
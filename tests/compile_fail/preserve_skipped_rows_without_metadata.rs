@@ -0,0 +1,11 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(preserve_skipped_rows)]
+struct Example {
+    hello: String,
+    #[field_names(skip)]
+    world: String,
+}
+
+fn main() {}
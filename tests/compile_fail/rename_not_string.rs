@@ -0,0 +1,9 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+struct Example {
+    #[field_names(rename = 123)]
+    hello: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+struct Example {
+    hello: String,
+    #[field_names(skip, rename = "x")]
+    hidden: bool,
+}
+
+fn main() {}
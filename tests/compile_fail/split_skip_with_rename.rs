@@ -0,0 +1,11 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+struct Example {
+    hello: String,
+    #[field_names(skip)]
+    #[field_names(rename = "x")]
+    hidden: bool,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use field_names::VariantNames;
+
+const BELOW: isize = -2;
+
+#[derive(VariantNames)]
+#[variant_names(tagged)]
+enum Example {
+    Below = BELOW,
+    AlsoBelow,
+    Zero,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use field_names::VariantNames;
+
+#[derive(VariantNames)]
+#[variant_names(tagged)]
+enum Example {
+    Below = -1,
+    Zero,
+}
+
+fn main() {}
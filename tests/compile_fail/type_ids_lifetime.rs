@@ -0,0 +1,9 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(type_ids)]
+struct Example<'a> {
+    hello: &'a str,
+}
+
+fn main() {}
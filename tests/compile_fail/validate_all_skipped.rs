@@ -0,0 +1,11 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(validate, skip_matching = "internal_*")]
+struct Example {
+    #[field_names(skip)]
+    hello: String,
+    internal_id: u64,
+}
+
+fn main() {}
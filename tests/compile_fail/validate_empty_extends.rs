@@ -0,0 +1,14 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(impl_trait)]
+struct Base {}
+
+#[derive(FieldNames)]
+#[field_names(validate, extends = "Base")]
+struct Example {
+    #[field_names(skip)]
+    hello: String,
+}
+
+fn main() {}
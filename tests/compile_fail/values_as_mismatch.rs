@@ -0,0 +1,16 @@
+use field_names::FieldNames;
+
+#[derive(Clone, Copy)]
+struct Meters(f64);
+
+#[derive(Clone, Copy)]
+struct Feet(f64);
+
+#[derive(FieldNames)]
+#[field_names(values_as = "Meters")]
+struct Route {
+    climb: Meters,
+    descent: Feet,
+}
+
+fn main() {}
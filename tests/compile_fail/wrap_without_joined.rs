@@ -0,0 +1,9 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(wrap = "[]")]
+struct Example {
+    hello: String,
+}
+
+fn main() {}
@@ -0,0 +1,41 @@
+//! Generated consts are resolved at type-check time, after every macro has expanded, so code
+//! from other derives and `const` items can read them wherever they appear.
+
+use field_names::{assert_same_fields, FieldNames};
+use serde::Serialize;
+
+// Read before either struct is declared.
+const _: () = assert!(FieldsFirst::FIELDS.len() == 2);
+const _: () = assert!(FieldsLast::FIELDS.len() == 2);
+const _: () = assert!(Extended::FIELDS.len() == 3);
+
+// `Extended` splices in the names of a type declared after it.
+#[derive(FieldNames)]
+#[field_names(extends = "FieldsLast", validate)]
+#[allow(dead_code)]
+struct Extended {
+    id: u64,
+}
+
+#[derive(FieldNames, Serialize)]
+#[allow(dead_code)]
+struct FieldsFirst {
+    hello: String,
+    world: String,
+}
+
+#[derive(Serialize, FieldNames)]
+#[field_names(impl_trait)]
+#[allow(dead_code)]
+struct FieldsLast {
+    hello: String,
+    world: String,
+}
+
+assert_same_fields!(FieldsFirst, FieldsLast);
+
+#[test]
+fn resolves_regardless_of_order() {
+    assert_eq!(FieldsFirst::FIELDS, FieldsLast::FIELDS);
+    assert_eq!(Extended::FIELDS, ["hello", "world", "id"]);
+}
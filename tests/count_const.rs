@@ -0,0 +1,22 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(count_const)]
+#[allow(dead_code)]
+pub struct HttpRequest {
+    method: String,
+    path: String,
+    #[field_names(skip)]
+    cache: (),
+}
+
+struct Columns<const N: usize>([&'static str; N]);
+
+#[test]
+fn count_const() {
+    let widths = [0usize; HTTP_REQUEST_FIELD_COUNT];
+    assert_eq!(widths.len(), 2);
+
+    let columns = Columns::<HTTP_REQUEST_FIELD_COUNT>(HttpRequest::FIELDS);
+    assert_eq!(columns.0, ["method", "path"]);
+}
@@ -0,0 +1,25 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(csv, joined = ",", rename_all = "camelCase")]
+#[allow(dead_code)]
+struct Row {
+    user_id: u64,
+    #[field_names(skip)]
+    password: String,
+    display_name: String,
+    email: String,
+}
+
+#[test]
+fn one_based_columns() {
+    assert_eq!(
+        Row::CSV_COLUMNS,
+        [("userId", 1), ("displayName", 2), ("email", 3)]
+    );
+}
+
+#[test]
+fn joined_header() {
+    assert_eq!(Row::FIELDS_JOINED, "userId,displayName,email");
+}
@@ -0,0 +1,70 @@
+//! `FIELDS[i]` is the name of the i-th emitted field in declaration order. Renames and skips
+//! never move the other fields, and the names are never sorted.
+
+use field_names::{FieldNames, VariantNames};
+
+#[derive(FieldNames)]
+#[field_names(
+    impl_trait,
+    skip(cache),
+    skip_matching = "tmp_*",
+    lowercase_const,
+    name_lens
+)]
+#[allow(dead_code)]
+struct Ordered {
+    zulu: u8,
+    #[field_names(skip)]
+    yankee: u8,
+    #[field_names(rename = "alpha")]
+    xray: u8,
+    cache: u8,
+    tmp_whiskey: u8,
+    #[field_names(rename = "Victor")]
+    victor: u8,
+    uniform: u8,
+}
+
+#[derive(FieldNames)]
+#[field_names(extends = "Ordered")]
+#[allow(dead_code)]
+struct Extended {
+    mike: u8,
+    bravo: u8,
+}
+
+#[derive(VariantNames)]
+#[allow(dead_code)]
+enum Variants {
+    Zulu,
+    #[variant_names(skip)]
+    Yankee,
+    Alpha,
+    Mike,
+}
+
+#[test]
+fn fields_follow_declaration_order() {
+    let expected = ["zulu", "alpha", "Victor", "uniform"];
+    assert_eq!(Ordered::FIELDS, expected);
+    assert_eq!(<Ordered as FieldNames>::FIELDS, expected);
+    assert_eq!(Ordered::all_field_names(), expected);
+    assert_eq!(
+        Ordered::FIELDS_LOWER,
+        ["zulu", "alpha", "victor", "uniform"]
+    );
+    assert_eq!(Ordered::FIELD_NAME_LENS, [4, 5, 6, 7]);
+}
+
+#[test]
+fn extended_fields_come_first() {
+    assert_eq!(
+        Extended::FIELDS,
+        ["zulu", "alpha", "Victor", "uniform", "mike", "bravo"]
+    );
+}
+
+#[test]
+fn variants_follow_declaration_order() {
+    assert_eq!(Variants::VARIANTS, ["Zulu", "Alpha", "Mike"]);
+}
@@ -0,0 +1,76 @@
+use field_names::VariantNames;
+
+#[derive(VariantNames, Default)]
+#[variant_names(
+    rename_all = "lowercase",
+    with_default_variant,
+    with_non_default_variants
+)]
+#[allow(dead_code)]
+enum Level {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+#[derive(VariantNames)]
+#[variant_names(with_default_variant, with_non_default_variants)]
+#[allow(dead_code)]
+enum NoDefault {
+    Yes,
+    No,
+}
+
+#[derive(VariantNames, Default)]
+#[variant_names(with_default_variant, with_non_default_variants)]
+#[allow(dead_code)]
+enum SkippedDefault {
+    #[default]
+    #[variant_names(skip)]
+    Unknown,
+    Known(u8),
+}
+
+#[derive(VariantNames, Default)]
+#[allow(dead_code)]
+enum OwnDefault {
+    #[default]
+    Yes,
+    No,
+}
+
+impl OwnDefault {
+    const DEFAULT_VARIANT: &'static str = "own";
+    const NON_DEFAULT_VARIANTS: &'static str = "own";
+}
+
+#[test]
+fn default_variant() {
+    assert_eq!(Level::DEFAULT_VARIANT, Some("medium"));
+    assert!(matches!(Level::default(), Level::Medium));
+}
+
+#[test]
+fn no_default_variant() {
+    assert_eq!(NoDefault::DEFAULT_VARIANT, None);
+}
+
+#[test]
+fn opt_in() {
+    assert_eq!(OwnDefault::DEFAULT_VARIANT, "own");
+    assert_eq!(OwnDefault::NON_DEFAULT_VARIANTS, "own");
+}
+
+#[test]
+fn skipped_default_variant() {
+    assert_eq!(SkippedDefault::DEFAULT_VARIANT, None);
+}
+
+#[test]
+fn non_default_variants() {
+    assert_eq!(Level::VARIANTS, ["low", "medium", "high"]);
+    assert_eq!(Level::NON_DEFAULT_VARIANTS, ["low", "high"]);
+    assert_eq!(NoDefault::NON_DEFAULT_VARIANTS, NoDefault::VARIANTS);
+    assert_eq!(SkippedDefault::NON_DEFAULT_VARIANTS, ["Known"]);
+}
@@ -0,0 +1,52 @@
+use field_names::FieldNames;
+use serde::Deserialize;
+
+#[derive(FieldNames, Deserialize)]
+#[field_names(with_defaultable)]
+#[allow(dead_code)]
+struct Fields {
+    id: u64,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default = "default_limit")]
+    limit: u32,
+}
+
+fn default_limit() -> u32 {
+    10
+}
+
+#[derive(FieldNames, Deserialize)]
+#[field_names(with_defaultable, from_serde, rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
+#[allow(dead_code)]
+struct Container {
+    user_id: u64,
+    #[serde(default = "default_limit")]
+    limit: u32,
+}
+
+impl Default for Container {
+    fn default() -> Self {
+        Self {
+            user_id: 0,
+            limit: default_limit(),
+        }
+    }
+}
+
+#[test]
+fn field_defaults() {
+    assert_eq!(
+        Fields::FIELDS_DEFAULTABLE,
+        [("id", false), ("tags", true), ("limit", true)]
+    );
+}
+
+#[test]
+fn container_default() {
+    assert_eq!(
+        Container::FIELDS_DEFAULTABLE,
+        [("userId", true), ("limit", true)]
+    );
+}
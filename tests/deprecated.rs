@@ -0,0 +1,21 @@
+#![allow(deprecated)]
+
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(with_deprecated)]
+#[allow(dead_code)]
+struct Example {
+    hello: String,
+    #[deprecated(note = "use `hello` instead")]
+    greeting: String,
+    world: String,
+}
+
+#[test]
+fn deprecated_fields() {
+    assert_eq!(
+        Example::FIELDS_DEPRECATED,
+        [("hello", false), ("greeting", true), ("world", false)]
+    );
+}
@@ -0,0 +1,28 @@
+use field_names::FieldNames;
+
+#[derive(Clone, FieldNames)]
+#[field_names(diff, rename_all = "camelCase")]
+struct Settings {
+    display_name: Option<String>,
+    timeout_secs: Option<u32>,
+    retries: u8,
+    #[field_names(skip)]
+    scratch: Vec<u8>,
+}
+
+#[test]
+fn changed_fields() {
+    let before = Settings {
+        display_name: None,
+        timeout_secs: Some(30),
+        retries: 3,
+        scratch: vec![],
+    };
+    let mut after = before.clone();
+    assert!(after.changed_fields(&before).is_empty());
+
+    after.display_name = Some("primary".into());
+    after.retries = 5;
+    after.scratch.push(1);
+    assert_eq!(after.changed_fields(&before), ["displayName", "retries"]);
+}
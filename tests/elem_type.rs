@@ -0,0 +1,70 @@
+use field_names::FieldNames;
+
+mod labels {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Label {
+        pub name: &'static str,
+        pub len: usize,
+    }
+
+    pub const fn label(name: &'static str) -> Label {
+        Label {
+            name,
+            len: name.len(),
+        }
+    }
+}
+
+use labels::Label;
+
+#[derive(FieldNames)]
+#[field_names(elem_type = "labels::Label", elem_ctor = "labels::label")]
+#[allow(dead_code)]
+struct Example {
+    hello: String,
+    #[field_names(rename = "planet")]
+    world: String,
+}
+
+#[derive(FieldNames)]
+#[field_names(impl_trait, elem_type = "Label", elem_ctor = "labels::label")]
+#[allow(dead_code)]
+struct Base {
+    id: u64,
+}
+
+#[derive(FieldNames)]
+#[field_names(extends = "Base", elem_type = "Label", elem_ctor = "labels::label")]
+#[allow(dead_code)]
+struct Extended {
+    name: String,
+}
+
+#[test]
+fn custom_elements() {
+    assert_eq!(
+        Example::FIELDS,
+        [
+            Label {
+                name: "hello",
+                len: 5,
+            },
+            Label {
+                name: "planet",
+                len: 6,
+            },
+        ]
+    );
+}
+
+#[test]
+fn trait_and_all_field_names_stay_str() {
+    assert_eq!(<Base as FieldNames>::FIELDS, ["id"]);
+    assert_eq!(Example::all_field_names(), ["hello", "planet"]);
+}
+
+#[test]
+fn extends() {
+    let names = Extended::FIELDS.map(|label| label.name);
+    assert_eq!(names, ["id", "name"]);
+}
@@ -0,0 +1,78 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(impl_trait)]
+#[allow(dead_code)]
+struct Base {
+    target: String,
+    lorem: String,
+}
+
+#[derive(FieldNames)]
+#[field_names(extends = "Base", impl_trait, type_and_fields)]
+#[allow(dead_code)]
+struct Extended {
+    ipsum: String,
+    #[field_names(skip)]
+    secret: bool,
+    dolor: String,
+}
+
+#[derive(FieldNames)]
+#[field_names(extends = "Extended", typed)]
+#[allow(dead_code)]
+struct Generic<T> {
+    value: T,
+}
+
+#[test]
+fn base_fields_come_first() {
+    assert_eq!(Extended::FIELDS, ["target", "lorem", "ipsum", "dolor"]);
+    assert_eq!(
+        <Extended as FieldNames>::FIELDS,
+        &["target", "lorem", "ipsum", "dolor"]
+    );
+}
+
+#[test]
+fn extends_chain() {
+    assert_eq!(Generic::<()>::FIELDS.len(), 5);
+    assert_eq!(Generic::<()>::FIELDS[0], "target");
+    assert_eq!(Generic::<()>::FIELDS[4], "value");
+}
+
+#[test]
+fn slice_helpers_include_base() {
+    assert_eq!(
+        Extended::type_and_fields().1,
+        &["target", "lorem", "ipsum", "dolor"]
+    );
+}
+
+#[derive(FieldNames)]
+#[field_names(impl_trait)]
+#[allow(dead_code)]
+struct Pair<T> {
+    left: T,
+    right: T,
+}
+
+#[derive(FieldNames)]
+#[field_names(extends = "Pair<u8>", type_and_fields)]
+#[allow(dead_code)]
+struct Labeled<T> {
+    label: T,
+}
+
+#[test]
+fn concrete_base_of_generic_struct() {
+    assert_eq!(Labeled::<()>::FIELDS, ["left", "right", "label"]);
+    assert_eq!(
+        Labeled::<()>::all_field_names(),
+        &["left", "right", "label"]
+    );
+    assert_eq!(
+        Labeled::<()>::type_and_fields().1,
+        &["left", "right", "label"]
+    );
+}
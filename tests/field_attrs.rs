@@ -0,0 +1,27 @@
+use field_names::FieldNames;
+use serde::Serialize;
+
+#[derive(FieldNames, Serialize)]
+#[field_names(with_attrs)]
+#[allow(dead_code)]
+struct Example {
+    #[serde(rename = "id")]
+    #[field_names(rename = "user_id")]
+    #[allow(unused)]
+    user: u64,
+    #[field_names(skip)]
+    #[serde(skip)]
+    secret: String,
+    plain: bool,
+}
+
+#[test]
+fn field_attrs() {
+    assert_eq!(
+        Example::FIELD_ATTRS,
+        [
+            ("user_id", &["serde(rename = \"id\")", "allow(unused)"][..]),
+            ("plain", &[][..]),
+        ]
+    );
+}
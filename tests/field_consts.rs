@@ -0,0 +1,31 @@
+use field_names::{FieldNames, VariantNames};
+
+#[derive(FieldNames)]
+#[field_names(impl_trait)]
+#[allow(dead_code)]
+struct Created {
+    id: u64,
+}
+
+#[derive(VariantNames)]
+#[variant_names(field_consts, rename_all = "kebab-case")]
+#[allow(dead_code)]
+enum Example {
+    Hello,
+    World {
+        planet: String,
+        #[variant_names(skip)]
+        secret: bool,
+    },
+    HttpStatus(u16, String),
+    #[variant_names(transparent)]
+    Created(Created),
+}
+
+#[test]
+fn field_consts() {
+    assert!(Example::HELLO_FIELDS.is_empty());
+    assert_eq!(Example::WORLD_FIELDS, ["planet"]);
+    assert_eq!(Example::HTTP_STATUS_FIELDS, ["0", "1"]);
+    assert_eq!(Example::CREATED_FIELDS, ["id"]);
+}
@@ -0,0 +1,34 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[allow(dead_code)]
+struct Example {
+    hello: String,
+    world: String,
+    #[field_names(skip)]
+    hidden: bool,
+}
+
+#[derive(FieldNames)]
+#[allow(dead_code)]
+struct Empty {
+    #[field_names(skip)]
+    hidden: bool,
+}
+
+/// `Empty` doesn't get a `FIELD_COUNT_NZ`, so this inherent const doesn't collide with one.
+impl Empty {
+    const FIELD_COUNT_NZ: Option<std::num::NonZeroUsize> = None;
+}
+
+#[test]
+fn non_empty() {
+    assert_eq!(Example::FIELD_COUNT_NZ.get(), 2);
+    assert_eq!(Example::FIELD_COUNT_NZ.get(), Example::FIELDS.len());
+}
+
+#[test]
+fn empty_omits_const() {
+    assert!(Empty::FIELDS.is_empty());
+    assert_eq!(Empty::FIELD_COUNT_NZ, None);
+}
@@ -0,0 +1,40 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(field_enum, rename_all = "camelCase")]
+#[allow(dead_code)]
+struct Example {
+    hello: String,
+    minutes_to_midnight: u32,
+    #[field_names(skip)]
+    hidden: bool,
+}
+
+#[derive(FieldNames)]
+#[field_names(field_enum = "Column")]
+#[allow(dead_code)]
+struct Row {
+    id: u64,
+}
+
+#[test]
+fn variants_follow_rust_names() {
+    assert_eq!(ExampleField::Hello.as_str(), "hello");
+    assert_eq!(
+        ExampleField::MinutesToMidnight.as_str(),
+        "minutesToMidnight"
+    );
+}
+
+#[test]
+fn display_uses_emitted_name() {
+    assert_eq!(
+        ExampleField::MinutesToMidnight.to_string(),
+        Example::FIELDS[1]
+    );
+}
+
+#[test]
+fn custom_name() {
+    assert_eq!(Column::Id.to_string(), "id");
+}
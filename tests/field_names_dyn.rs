@@ -0,0 +1,31 @@
+use field_names::{FieldNames, FieldNamesDyn};
+
+#[derive(FieldNames)]
+#[field_names(impl_trait)]
+#[allow(dead_code)]
+struct Person {
+    name: String,
+    age: u8,
+}
+
+#[derive(FieldNames)]
+#[field_names(impl_trait)]
+#[allow(dead_code)]
+struct Address {
+    street: String,
+}
+
+#[test]
+fn through_trait_object() {
+    let person = Person {
+        name: "Ada".into(),
+        age: 36,
+    };
+    let address = Address {
+        street: "Main".into(),
+    };
+
+    let values: [&dyn FieldNamesDyn; 2] = [&person, &address];
+    assert_eq!(values[0].field_names_dyn(), ["name", "age"]);
+    assert_eq!(values[1].field_names_dyn(), ["street"]);
+}
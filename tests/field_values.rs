@@ -0,0 +1,45 @@
+use field_names::FieldNames;
+
+#[derive(Clone, Copy, FieldNames)]
+#[field_names(values_as = "f64")]
+struct Sample {
+    cpu: f64,
+    memory: f64,
+    #[field_names(rename = "disk_io")]
+    disk: f64,
+}
+
+#[test]
+fn uniform() {
+    let sample = Sample {
+        cpu: 0.5,
+        memory: 128.0,
+        disk: 3.25,
+    };
+
+    assert_eq!(
+        sample.field_values(),
+        [("cpu", 0.5), ("memory", 128.0), ("disk_io", 3.25)]
+    );
+}
+
+#[derive(FieldNames)]
+#[field_names(values_as = "f64")]
+#[allow(dead_code)]
+struct Mixed {
+    ratio: f32,
+    count: u32,
+    #[field_names(skip)]
+    label: String,
+}
+
+#[test]
+fn converted_with_into() {
+    let mixed = Mixed {
+        ratio: 0.25,
+        count: 7,
+        label: "ignored".into(),
+    };
+
+    assert_eq!(mixed.field_values(), [("ratio", 0.25), ("count", 7.0)]);
+}
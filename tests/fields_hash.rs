@@ -0,0 +1,47 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(hash)]
+#[allow(dead_code)]
+struct V1 {
+    id: u64,
+    name: String,
+}
+
+mod same {
+    #[derive(field_names::FieldNames)]
+    #[field_names(hash)]
+    #[allow(dead_code)]
+    pub struct V1Copy {
+        id: u32,
+        name: &'static str,
+        #[field_names(skip)]
+        cached: bool,
+    }
+
+    pub const HASH: u64 = V1Copy::FIELDS_HASH;
+}
+
+#[derive(FieldNames)]
+#[field_names(hash)]
+#[allow(dead_code)]
+struct V2 {
+    id: u64,
+    name: String,
+    email: String,
+}
+
+#[test]
+fn stable_value() {
+    assert_eq!(V1::FIELDS_HASH, 0x971b_c954_3163_30c3);
+}
+
+#[test]
+fn same_names_same_hash() {
+    assert_eq!(V1::FIELDS_HASH, same::HASH);
+}
+
+#[test]
+fn adding_a_field_changes_the_hash() {
+    assert_ne!(V1::FIELDS_HASH, V2::FIELDS_HASH);
+}
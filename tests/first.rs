@@ -0,0 +1,27 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(first(target, lorem), with_span_info)]
+#[allow(dead_code)]
+struct Pinned {
+    ipsum: String,
+    lorem: String,
+    dolor: String,
+    target: String,
+    #[field_names(skip)]
+    secret: bool,
+}
+
+#[test]
+fn pinned_fields_lead() {
+    assert_eq!(Pinned::FIELDS, ["target", "lorem", "ipsum", "dolor"]);
+}
+
+#[test]
+fn parallel_arrays_follow() {
+    let names = Pinned::FIELD_LOCATIONS
+        .iter()
+        .map(|location| location.name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, Pinned::FIELDS);
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "from_pairs")]
+
+use field_names::{FieldNames, SetFieldError};
+
+#[derive(FieldNames)]
+#[field_names(from_pairs, rename_all = "camelCase")]
+#[allow(dead_code)]
+struct Example {
+    user_id: u64,
+    display_name: String,
+    #[field_names(skip)]
+    secret: String,
+}
+
+#[test]
+fn known_pairs() {
+    let partial = Example::from_pairs(vec![("userId", "7"), ("displayName", "Ada")]).unwrap();
+    assert_eq!(
+        partial,
+        PartialExample {
+            user_id: Some("7"),
+            display_name: Some("Ada"),
+        }
+    );
+}
+
+#[test]
+fn missing_and_repeated_pairs() {
+    let partial = Example::from_pairs([("userId", "1"), ("userId", "2")]).unwrap();
+    assert_eq!(partial.user_id, Some("2"));
+    assert_eq!(partial.display_name, None);
+    assert_eq!(Example::from_pairs([]).unwrap(), PartialExample::default());
+}
+
+#[test]
+fn unknown_pairs() {
+    assert_eq!(
+        Example::from_pairs([("userId", "1"), ("secret", "hunter2")]),
+        Err(SetFieldError::UnknownField("secret".to_string()))
+    );
+    assert_eq!(
+        Example::from_pairs([("user_id", "1")]),
+        Err(SetFieldError::UnknownField("user_id".to_string()))
+    );
+}
@@ -0,0 +1,144 @@
+use field_names::FieldNames;
+use serde::Serialize;
+
+#[derive(FieldNames, Serialize)]
+#[field_names(impl_trait, from_serde)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+struct Metadata {
+    created_at: u64,
+    #[serde(rename = "by")]
+    author: String,
+}
+
+#[derive(FieldNames, Serialize)]
+#[field_names(from_serde)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+struct Skipped {
+    post_id: u64,
+    #[serde(flatten)]
+    metadata: Metadata,
+    #[serde(skip)]
+    cache: Vec<u8>,
+    body_text: String,
+}
+
+#[derive(FieldNames, Serialize)]
+#[field_names(from_serde, flatten_serde = "recurse")]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+struct Recursed {
+    post_id: u64,
+    #[serde(flatten)]
+    metadata: Metadata,
+    #[serde(skip_serializing)]
+    cache: Vec<u8>,
+    body_text: String,
+}
+
+fn wire_names<T: Serialize>(value: &T) -> Vec<String> {
+    match serde_json::to_value(value).unwrap() {
+        serde_json::Value::Object(map) => map.keys().cloned().collect(),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn flatten_skip_is_default() {
+    assert_eq!(Skipped::FIELDS, ["postId", "bodyText"]);
+}
+
+#[test]
+fn flatten_recurse() {
+    assert_eq!(Recursed::FIELDS, ["postId", "createdAt", "by", "bodyText"]);
+
+    let value = Recursed {
+        post_id: 1,
+        metadata: Metadata {
+            created_at: 2,
+            author: "Ada".into(),
+        },
+        cache: vec![],
+        body_text: "Hello".into(),
+    };
+    let mut expected = Recursed::FIELDS
+        .iter()
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>();
+    expected.sort();
+    assert_eq!(wire_names(&value), expected);
+}
+
+#[derive(FieldNames, Serialize)]
+#[field_names(from_serde, flatten_serde = "recurse", extends = "Metadata", typed)]
+#[allow(dead_code)]
+struct ExtendedAndFlattened {
+    #[serde(flatten)]
+    inner: Metadata,
+    id: u64,
+    #[serde(flatten)]
+    again: Metadata,
+}
+
+#[test]
+fn extends_and_recurse() {
+    assert_eq!(
+        ExtendedAndFlattened::FIELDS,
+        [
+            "createdAt",
+            "by",
+            "createdAt",
+            "by",
+            "id",
+            "createdAt",
+            "by"
+        ]
+    );
+}
+
+#[derive(FieldNames, Serialize)]
+#[field_names(from_serde)]
+#[serde(rename_all = "camelCase")]
+struct Raw {
+    r#type: u8,
+    r#match_kind: u8,
+}
+
+#[test]
+fn raw_identifiers() {
+    let value = Raw {
+        r#type: 0,
+        r#match_kind: 0,
+    };
+    assert_eq!(Raw::FIELDS, ["type", "matchKind"]);
+    let mut expected = Raw::FIELDS
+        .iter()
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>();
+    expected.sort();
+    assert_eq!(wire_names(&value), expected);
+}
+
+/// With `heck`, only a `rename_all` with `words = "heck"` uses its word splitting; a rule
+/// read from `#[serde(rename_all)]` still has to produce serde's wire names.
+#[cfg(feature = "heck")]
+#[derive(FieldNames, Serialize)]
+#[field_names(from_serde)]
+#[serde(rename_all = "snake_case")]
+#[allow(non_snake_case)]
+struct Acronyms {
+    HTTPStatus: u16,
+    retry_after: u64,
+}
+
+#[cfg(feature = "heck")]
+#[test]
+fn serde_rename_all_ignores_heck() {
+    let value = Acronyms {
+        HTTPStatus: 200,
+        retry_after: 0,
+    };
+    assert_eq!(Acronyms::FIELDS, ["HTTPStatus", "retry_after"]);
+    assert_eq!(wire_names(&value), Acronyms::FIELDS);
+}
@@ -0,0 +1,78 @@
+//! Derives on structs declared inside function bodies, where the generated code can only rely
+//! on absolute paths.
+
+use field_names::FieldNamesDyn;
+
+#[test]
+fn inherent() {
+    #[derive(field_names::FieldNames)]
+    #[field_names(typed, field_enum, missing, lowercase_const)]
+    #[allow(dead_code)]
+    struct Local {
+        hello: String,
+        world: Option<u8>,
+    }
+
+    assert_eq!(Local::FIELDS, ["hello", "world"]);
+    assert_eq!(LocalField::World.as_str(), "world");
+}
+
+#[test]
+fn trait_impl() {
+    #[derive(field_names::FieldNames)]
+    #[field_names(impl_trait)]
+    #[allow(dead_code)]
+    struct Base {
+        id: u64,
+    }
+
+    #[derive(field_names::FieldNames)]
+    #[field_names(impl_trait, extends = "Base")]
+    #[allow(dead_code)]
+    struct Local {
+        hello: String,
+    }
+
+    fn names<T: field_names::FieldNames>() -> &'static [&'static str] {
+        T::FIELDS
+    }
+
+    let local = Local {
+        hello: String::new(),
+    };
+    assert_eq!(names::<Local>(), ["id", "hello"]);
+    assert_eq!(
+        (&local as &dyn FieldNamesDyn).field_names_dyn(),
+        ["id", "hello"]
+    );
+}
+
+#[test]
+fn variants() {
+    #[derive(field_names::VariantNames)]
+    #[variant_names(with_fields, with_arity)]
+    #[allow(dead_code)]
+    enum Local {
+        Hello { world: u8 },
+    }
+
+    assert_eq!(Local::VARIANTS, ["Hello"]);
+}
+
+#[test]
+fn shadowed_prelude() {
+    #[allow(dead_code)]
+    trait Debug {}
+    #[allow(dead_code)]
+    struct Option;
+
+    #[derive(field_names::FieldNames)]
+    #[field_names(field_enum, missing)]
+    #[allow(dead_code)]
+    struct Local {
+        hello: core::option::Option<String>,
+    }
+
+    assert_eq!(Local::FIELDS, ["hello"]);
+    assert_eq!(format!("{:?}", LocalField::Hello), "Hello");
+}
@@ -0,0 +1,107 @@
+//! The generated methods of structs with several lifetimes, type parameters and const
+//! parameters, including bounds in `where` clauses and defaults.
+
+use std::{borrow::Cow, fmt::Debug};
+
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(
+    missing,
+    diff,
+    type_and_fields,
+    lowercase_lookup,
+    with_module_path,
+    wrapper = "cow",
+    values_as = "f64",
+    sync_with = "Flat"
+)]
+#[allow(dead_code)]
+struct Reading<'a, 'b: 'a, T, U: Copy = u8, const N: usize = 2>
+where
+    T: Copy + Into<f64> + PartialEq + Debug,
+    U: Into<f64> + PartialEq,
+{
+    value: T,
+    scale: U,
+    #[field_names(skip)]
+    label: &'a str,
+    #[field_names(skip)]
+    tags: &'b [&'a str; N],
+    #[field_names(skip)]
+    unit: Option<Cow<'a, str>>,
+}
+
+#[derive(FieldNames)]
+#[allow(dead_code)]
+struct Flat {
+    value: f64,
+    scale: f64,
+}
+
+fn reading<'a>(value: f32, scale: u8, tags: &'a [&'a str; 2]) -> Reading<'a, 'a, f32> {
+    Reading {
+        value,
+        scale,
+        label: "temp",
+        tags,
+        unit: None,
+    }
+}
+
+#[test]
+fn value_methods() {
+    let tags = ["a", "b"];
+    let first = reading(1.5, 2, &tags);
+    let second = reading(1.5, 3, &tags);
+
+    assert_eq!(first.field_values(), [("value", 1.5), ("scale", 2.0)]);
+    assert_eq!(first.changed_fields(&second), ["scale"]);
+}
+
+#[test]
+fn associated_functions() {
+    type R<'x> = Reading<'x, 'x, f32>;
+
+    assert_eq!(R::field_index("SCALE"), Some(1));
+    assert!(R::contains_field("value"));
+    assert_eq!(R::fields()[0], "value");
+    assert!(R::type_path().ends_with("::Reading"));
+    assert_eq!(R::type_and_fields().1, ["value", "scale"]);
+    assert!(R::type_and_fields().0.contains("Reading"));
+}
+
+#[derive(FieldNames)]
+#[field_names(missing)]
+#[allow(dead_code)]
+struct Options<'a, T> {
+    name: Option<&'a str>,
+    limit: Option<T>,
+}
+
+#[derive(FieldNames)]
+#[field_names(setters)]
+#[allow(dead_code)]
+struct Settings<'a, T: std::str::FromStr> {
+    name: String,
+    limit: T,
+    #[field_names(skip)]
+    source: &'a str,
+}
+
+#[test]
+fn params_with_bounds() {
+    let options = Options::<u32> {
+        name: Some("x"),
+        limit: None,
+    };
+    assert_eq!(options.missing_fields(), ["limit"]);
+
+    let mut settings = Settings::<u32> {
+        name: String::new(),
+        limit: 0,
+        source: "cli",
+    };
+    settings.set_field_from_str("limit", "10").unwrap();
+    assert_eq!(settings.limit, 10);
+}
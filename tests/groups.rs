@@ -0,0 +1,24 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[allow(dead_code)]
+struct Server {
+    name: String,
+    #[field_names(group = "network")]
+    host: String,
+    #[field_names(group = "network")]
+    port: u16,
+    #[field_names(group = "storage")]
+    data_dir: String,
+    #[field_names(skip, group = "network")]
+    socket: (),
+    verbose: bool,
+}
+
+#[test]
+fn fields_in_group() {
+    assert_eq!(Server::fields_in_group("network"), ["host", "port"]);
+    assert_eq!(Server::fields_in_group("storage"), ["data_dir"]);
+    assert_eq!(Server::fields_in_group("default"), ["name", "verbose"]);
+    assert!(Server::fields_in_group("missing").is_empty());
+}
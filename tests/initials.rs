@@ -0,0 +1,57 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(initials)]
+#[allow(dead_code)]
+struct First {
+    display_name: String,
+    minutes_to_midnight: u32,
+    #[field_names(skip)]
+    secret: String,
+    id: u64,
+}
+
+#[derive(FieldNames)]
+#[field_names(initials = "words")]
+#[allow(dead_code)]
+struct Words {
+    display_name: String,
+    minutes_to_midnight: u32,
+    #[field_names(rename = "httpStatusCode")]
+    status: u16,
+    id: u64,
+}
+
+#[derive(FieldNames)]
+#[field_names(initials, rename_all = "camelCase", prefix = "x_", suffix = "_v1")]
+#[allow(dead_code)]
+struct Prefixed {
+    display_name: String,
+    #[field_names(rename = "Status")]
+    status: u16,
+}
+
+#[derive(FieldNames)]
+#[field_names(initials = "words", prefix = "x-")]
+#[allow(dead_code)]
+struct PrefixedWords {
+    display_name: String,
+    minutes_to_midnight: u32,
+}
+
+#[test]
+fn first_characters() {
+    assert_eq!(First::FIELD_INITIALS, ["d", "m", "i"]);
+}
+
+#[test]
+fn word_initials() {
+    assert_eq!(Words::FIELD_INITIALS, ["DN", "MTM", "HSC", "I"]);
+}
+
+#[test]
+fn prefix_and_suffix_are_ignored() {
+    assert_eq!(Prefixed::FIELDS, ["x_displayName_v1", "x_Status_v1"]);
+    assert_eq!(Prefixed::FIELD_INITIALS, ["d", "S"]);
+    assert_eq!(PrefixedWords::FIELD_INITIALS, ["DN", "MTM"]);
+}
@@ -0,0 +1,38 @@
+//! `FIELDS` and `VARIANTS` are arrays, which implement `IntoIterator` by value, so they can be
+//! looped over directly without a marker type or `.iter()`.
+
+use field_names::{FieldNames, VariantNames};
+
+#[derive(FieldNames)]
+#[allow(dead_code)]
+struct Example {
+    hello: String,
+    world: String,
+}
+
+#[derive(VariantNames)]
+#[allow(dead_code)]
+enum Greeting {
+    Hello,
+    World,
+}
+
+#[test]
+fn fields_without_iter() {
+    let mut names = Vec::new();
+    for name in Example::FIELDS {
+        names.push(name);
+    }
+
+    assert_eq!(names, ["hello", "world"]);
+}
+
+#[test]
+fn variants_without_iter() {
+    let mut lengths = Vec::new();
+    for name in Greeting::VARIANTS {
+        lengths.push(name.len());
+    }
+
+    assert_eq!(lengths, [5, 5]);
+}
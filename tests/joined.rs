@@ -0,0 +1,43 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(joined = ", ")]
+#[allow(dead_code)]
+struct Example {
+    hello: String,
+    world: String,
+    minutes_to_midnight: u32,
+    #[field_names(skip)]
+    hidden: (),
+}
+
+#[test]
+fn joined() {
+    assert_eq!(Example::FIELDS_JOINED, "hello, world, minutes_to_midnight");
+}
+
+#[derive(FieldNames)]
+#[field_names(joined = " | ", wrap = "[]")]
+#[allow(dead_code)]
+struct Wrapped {
+    hello: String,
+    world: String,
+}
+
+#[derive(FieldNames)]
+#[field_names(joined = ",", wrap = "{{}}")]
+#[allow(dead_code)]
+struct WideWrap {
+    hello: String,
+}
+
+#[derive(FieldNames)]
+#[field_names(joined = ", ", wrap = "[]")]
+struct Empty {}
+
+#[test]
+fn wrapped() {
+    assert_eq!(Wrapped::FIELDS_JOINED, "[hello | world]");
+    assert_eq!(WideWrap::FIELDS_JOINED, "{{hello}}");
+    assert_eq!(Empty::FIELDS_JOINED, "[]");
+}
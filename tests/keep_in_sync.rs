@@ -15,6 +15,9 @@
 //! ad-hoc syntax for struct declaration as they find themselves needing to further differentiate
 //! the sibling structs.
 //!
+//! `#[field_names(sync_with = "Raw", omit = "lorem_computation")]` on `Base` generates a test like
+//! `base_and_raw_not_in_sync` below; see `tests/sync_with.rs`.
+//!
 //! # Conditional Compilation
 //! If `FieldNames` is only being used for unit tests, include it under `dev-dependencies` and use
 //! `#[cfg_attr(test, derive(FieldNames))]` to avoid any binary size cost in the built crate.
@@ -28,6 +31,7 @@ use std::{
 use field_names::FieldNames;
 
 #[derive(FieldNames, PartialEq, Eq, PartialOrd, Ord)]
+#[field_names(impl_trait)]
 #[allow(dead_code)]
 struct Base {
     target: IpAddr,
@@ -65,6 +69,7 @@ impl TryFrom<Raw> for Base {
 /// 1. Two `View` instances will be equal if and only if their `Base` instances are equal.
 /// 2. Ordering `View` instances will produce the same ordering as ordering their `Base` instances.
 #[derive(FieldNames, PartialEq, Eq, PartialOrd, Ord)]
+#[field_names(impl_trait)]
 #[allow(dead_code)]
 struct View<'a> {
     // Field order matters to the derived `PartialOrd` trait, so it must stay in sync with `Base`.
@@ -75,6 +80,7 @@ struct View<'a> {
 }
 
 #[derive(FieldNames)]
+#[field_names(impl_trait)]
 #[allow(dead_code)]
 struct PartialView<'a> {
     lorem: &'a str,
@@ -147,3 +153,16 @@ fn base_and_raw_not_in_sync() {
         .collect::<Vec<_>>();
     assert_eq!(base_fields, raw_fields);
 }
+
+/// When field order doesn't matter but every field must exist in both, `index_map` says where
+/// each of `PartialView`'s fields lives in `Base`, so values can be permuted from one to the
+/// other.
+#[test]
+fn partial_view_permutes_into_base() {
+    let positions = field_names::index_map::<PartialView, Base>();
+    assert_eq!(positions, [Some(1), Some(0)]);
+
+    let view_positions = field_names::index_map::<View, Base>();
+    assert_eq!(view_positions, [Some(0), Some(1), Some(2), Some(3)]);
+    assert_eq!(field_names::index_map::<Base, View>()[4], None);
+}
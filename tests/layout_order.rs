@@ -0,0 +1,27 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(layout_order)]
+#[repr(C)]
+#[allow(dead_code)]
+struct Header {
+    magic: u32,
+    flags: u8,
+    length: u64,
+    version: u16,
+}
+
+#[derive(FieldNames)]
+#[field_names(layout_order)]
+#[repr(C, align(8))]
+#[allow(dead_code)]
+struct Aligned {
+    tag: u8,
+    value: u32,
+}
+
+#[test]
+fn repr_c_is_declaration_order() {
+    assert_eq!(Header::FIELDS, ["magic", "flags", "length", "version"]);
+    assert_eq!(Aligned::FIELDS, ["tag", "value"]);
+}
@@ -0,0 +1,29 @@
+//! The inherent consts of a struct with lifetime parameters are reachable without naming a
+//! lifetime, whether in a function body, a `const` item, or through the trait.
+
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(impl_trait, joined = ",")]
+#[allow(dead_code)]
+struct View<'a, 'b: 'a> {
+    name: &'a str,
+    tags: &'b [&'a str],
+}
+
+const VIEW_FIELDS: [&str; 2] = View::FIELDS;
+const VIEW_JOINED: &str = View::FIELDS_JOINED;
+
+fn trait_fields<T: FieldNames>() -> &'static [&'static str] {
+    T::FIELDS
+}
+
+#[test]
+fn elided_lifetimes() {
+    assert_eq!(View::FIELDS, ["name", "tags"]);
+    assert_eq!(View::<'_, '_>::FIELDS, View::FIELDS);
+    assert_eq!(VIEW_FIELDS, View::FIELDS);
+    assert_eq!(VIEW_JOINED, "name,tags");
+    assert_eq!(<View as FieldNames>::FIELDS, ["name", "tags"]);
+    assert_eq!(trait_fields::<View>(), ["name", "tags"]);
+}
@@ -0,0 +1,15 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(rename_all = "PascalCase", lowercase_const)]
+#[allow(dead_code)]
+struct Example {
+    hello: String,
+    minutes_to_midnight: u32,
+}
+
+#[test]
+fn lowercased_alongside_original() {
+    assert_eq!(Example::FIELDS, ["Hello", "MinutesToMidnight"]);
+    assert_eq!(Example::FIELDS_LOWER, ["hello", "minutestomidnight"]);
+}
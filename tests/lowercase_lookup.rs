@@ -0,0 +1,98 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(lowercase_lookup, rename_all = "camelCase")]
+#[allow(dead_code)]
+struct Example {
+    user_id: u64,
+    #[field_names(skip)]
+    secret: String,
+    display_name: String,
+    #[field_names(rename = "Größe")]
+    size: u32,
+}
+
+#[derive(FieldNames)]
+#[field_names(impl_trait)]
+#[allow(dead_code)]
+struct Base {
+    #[field_names(rename = "Id")]
+    id: u64,
+}
+
+#[derive(FieldNames)]
+#[field_names(extends = "Base", lowercase_lookup)]
+#[allow(dead_code)]
+struct Extended {
+    #[field_names(rename = "Name")]
+    name: String,
+}
+
+#[test]
+fn fields_keep_casing() {
+    assert_eq!(Example::FIELDS, ["userId", "displayName", "Größe"]);
+}
+
+#[test]
+fn mixed_case_lookups() {
+    assert_eq!(Example::field_index("userId"), Some(0));
+    assert_eq!(Example::field_index("USERID"), Some(0));
+    assert_eq!(Example::field_index("DisplayName"), Some(1));
+    assert_eq!(Example::field_index("GRÖSSE"), None);
+    assert_eq!(Example::field_index("GRÖßE"), Some(2));
+    assert!(Example::contains_field("displayname"));
+    assert!(Example::contains_field("größe"));
+}
+
+#[test]
+fn unknown_names() {
+    assert_eq!(Example::field_index("secret"), None);
+    assert_eq!(Example::field_index("user_id"), None);
+    assert!(!Example::contains_field(""));
+    assert!(!Example::contains_field("userIdx"));
+}
+
+#[test]
+fn extended_lookups() {
+    assert_eq!(Extended::field_index("id"), Some(0));
+    assert_eq!(Extended::field_index("NAME"), Some(1));
+}
+
+/// Every way of skipping a field keeps it out of the lookups, in any casing.
+#[derive(FieldNames, serde::Serialize)]
+#[field_names(
+    lowercase_lookup,
+    from_serde,
+    skip_doc_hidden,
+    skip(by_list),
+    skip_matching = "internal_*"
+)]
+#[allow(dead_code)]
+struct Skips {
+    kept: u8,
+    #[field_names(skip)]
+    by_attribute: u8,
+    by_list: u8,
+    internal_cache: u8,
+    #[serde(skip)]
+    by_serde: u8,
+    #[doc(hidden)]
+    by_doc_hidden: u8,
+}
+
+#[test]
+fn skipped_fields_are_not_found() {
+    assert_eq!(Skips::FIELDS, ["kept"]);
+    for name in [
+        "by_attribute",
+        "by_list",
+        "internal_cache",
+        "by_serde",
+        "by_doc_hidden",
+        "BY_ATTRIBUTE",
+    ] {
+        assert_eq!(Skips::field_index(name), None, "{}", name);
+        assert!(!Skips::contains_field(name), "{}", name);
+    }
+    assert_eq!(Skips::field_index("KEPT"), Some(0));
+}
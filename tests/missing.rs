@@ -0,0 +1,36 @@
+use field_names::FieldNames;
+
+#[derive(Default, FieldNames)]
+#[field_names(missing)]
+struct Builder {
+    name: Option<String>,
+    port: std::option::Option<u16>,
+    retries: u8,
+    #[field_names(skip)]
+    cache: Option<Vec<u8>>,
+    timeout: Option<u32>,
+}
+
+#[test]
+fn half_built() {
+    let builder = Builder {
+        name: Some("example".into()),
+        ..Default::default()
+    };
+
+    assert_eq!(builder.retries, 0);
+    assert!(builder.cache.is_none());
+    assert_eq!(builder.missing_fields(), vec!["port", "timeout"]);
+}
+
+#[test]
+fn fully_built() {
+    let builder = Builder {
+        name: Some("example".into()),
+        port: Some(80),
+        timeout: Some(30),
+        ..Default::default()
+    };
+
+    assert!(builder.missing_fields().is_empty());
+}
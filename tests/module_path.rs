@@ -0,0 +1,42 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(with_module_path, with_type_name)]
+#[allow(dead_code)]
+struct Example {
+    hello: String,
+}
+
+mod outer {
+    pub mod inner {
+        #[derive(field_names::FieldNames)]
+        #[field_names(with_module_path)]
+        #[allow(dead_code)]
+        pub struct Nested<T> {
+            value: T,
+        }
+
+        pub fn nested_type_path() -> &'static str {
+            Nested::<u8>::type_path()
+        }
+    }
+}
+
+#[test]
+fn type_path() {
+    assert_eq!(Example::type_path(), "module_path::Example");
+}
+
+#[test]
+fn includes_defining_module() {
+    assert_eq!(
+        outer::inner::nested_type_path(),
+        "module_path::outer::inner::Nested"
+    );
+}
+
+#[test]
+fn usable_in_const() {
+    const PATH: &str = Example::type_path();
+    assert!(PATH.ends_with(Example::TYPE_NAME));
+}
@@ -0,0 +1,87 @@
+use field_names::VariantNames;
+
+const BASE: i32 = 40;
+
+#[derive(VariantNames)]
+#[variant_names(rename_all = "lowercase", with_discriminant_lookup)]
+#[allow(dead_code)]
+#[repr(i32)]
+enum Status {
+    Pending,
+    Active,
+    Suspended = 10,
+    #[variant_names(skip)]
+    Internal,
+    Closed,
+    Negative = -3,
+    Archived = BASE,
+    Deleted,
+}
+
+#[derive(VariantNames)]
+#[variant_names(with_discriminant_lookup)]
+#[allow(dead_code)]
+enum Implicit {
+    First,
+    Second,
+}
+
+#[derive(VariantNames)]
+#[allow(dead_code)]
+enum OwnLookup {
+    First = 1,
+    Second = 2,
+}
+
+impl OwnLookup {
+    fn name_from_discriminant(d: i64) -> &'static str {
+        if d == 1 {
+            "one"
+        } else {
+            "other"
+        }
+    }
+}
+
+#[test]
+fn explicit_and_implicit() {
+    assert_eq!(Status::name_from_discriminant(0), Some("pending"));
+    assert_eq!(Status::name_from_discriminant(1), Some("active"));
+    assert_eq!(Status::name_from_discriminant(10), Some("suspended"));
+    assert_eq!(Status::name_from_discriminant(12), Some("closed"));
+    assert_eq!(Status::name_from_discriminant(-3), Some("negative"));
+    assert_eq!(Status::name_from_discriminant(40), Some("archived"));
+    assert_eq!(Status::name_from_discriminant(41), Some("deleted"));
+}
+
+#[test]
+fn unknown_discriminant() {
+    assert_eq!(Status::name_from_discriminant(2), None);
+    assert_eq!(Status::name_from_discriminant(100), None);
+    assert_eq!(Status::name_from_discriminant(-1), None);
+}
+
+#[test]
+fn skipped_variant() {
+    assert_eq!(Status::name_from_discriminant(11), None);
+}
+
+#[test]
+fn matches_cast() {
+    assert_eq!(
+        Status::name_from_discriminant(Status::Deleted as i64),
+        Some("deleted")
+    );
+}
+
+#[test]
+fn implicit_only() {
+    assert_eq!(Implicit::name_from_discriminant(0), Some("First"));
+    assert_eq!(Implicit::name_from_discriminant(1), Some("Second"));
+    assert_eq!(Implicit::name_from_discriminant(2), None);
+}
+
+#[test]
+fn opt_in() {
+    assert_eq!(OwnLookup::name_from_discriminant(1), "one");
+}
@@ -0,0 +1,19 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(name_lens, rename_all = "camelCase")]
+#[allow(dead_code)]
+struct Example {
+    id: u64,
+    display_name: String,
+    #[field_names(rename = "naïve")]
+    naive: bool,
+}
+
+#[test]
+fn name_lens() {
+    assert_eq!(Example::FIELD_NAME_LENS, [2, 11, 6]);
+    for (name, len) in Example::FIELDS.iter().zip(Example::FIELD_NAME_LENS) {
+        assert_eq!(name.len(), len);
+    }
+}
@@ -0,0 +1,32 @@
+use field_names::Names;
+
+#[derive(Names)]
+#[field_names(rename_all = "camelCase")]
+#[allow(dead_code)]
+struct Example {
+    first_name: String,
+    #[field_names(skip)]
+    hidden: bool,
+}
+
+#[derive(Names)]
+#[variant_names(with_fields)]
+#[allow(dead_code)]
+enum Shape {
+    Circle {
+        radius: f64,
+    },
+    #[variant_names(skip)]
+    Empty,
+    Point,
+}
+
+#[test]
+fn dispatches_on_input() {
+    assert_eq!(Example::FIELDS, ["firstName"]);
+    assert_eq!(Shape::VARIANTS, ["Circle", "Point"]);
+    assert_eq!(
+        Shape::VARIANT_FIELDS,
+        [("Circle", &["radius"][..]), ("Point", &[][..])]
+    );
+}
@@ -0,0 +1,81 @@
+use field_names::{FieldNames, VariantNames};
+
+#[derive(FieldNames)]
+#[field_names(with_non_exhaustive)]
+#[allow(dead_code)]
+struct Closed {
+    id: u64,
+}
+
+#[derive(FieldNames)]
+#[field_names(with_non_exhaustive)]
+#[non_exhaustive]
+#[allow(dead_code)]
+struct Open {
+    id: u64,
+}
+
+#[derive(VariantNames)]
+#[variant_names(with_non_exhaustive)]
+#[allow(dead_code)]
+enum ClosedChoice {
+    Yes,
+    No,
+}
+
+#[derive(VariantNames)]
+#[variant_names(with_non_exhaustive)]
+#[non_exhaustive]
+#[allow(dead_code)]
+enum OpenChoice {
+    Yes,
+    No,
+}
+
+#[derive(VariantNames)]
+#[variant_names(with_non_exhaustive)]
+#[allow(dead_code)]
+enum OpenVariant {
+    #[non_exhaustive]
+    Yes {
+        reason: String,
+    },
+    No,
+}
+
+#[test]
+fn structs() {
+    assert_eq!(
+        [Closed::NON_EXHAUSTIVE, Open::NON_EXHAUSTIVE],
+        [false, true]
+    );
+}
+
+#[test]
+fn enums() {
+    assert_eq!(
+        [ClosedChoice::NON_EXHAUSTIVE, OpenChoice::NON_EXHAUSTIVE],
+        [false, true]
+    );
+}
+
+#[test]
+fn variant_attribute_is_not_the_enum() {
+    let non_exhaustive = OpenVariant::NON_EXHAUSTIVE;
+    assert!(!non_exhaustive);
+}
+
+#[derive(FieldNames)]
+#[allow(dead_code)]
+struct OwnConst {
+    id: u64,
+}
+
+impl OwnConst {
+    const NON_EXHAUSTIVE: &'static str = "own";
+}
+
+#[test]
+fn opt_in() {
+    assert_eq!(OwnConst::NON_EXHAUSTIVE, "own");
+}
@@ -0,0 +1,50 @@
+use std::rc::Rc;
+
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(only_impl = "Copy")]
+#[allow(dead_code)]
+struct Example {
+    id: u64,
+    name: String,
+    score: f32,
+    #[field_names(skip)]
+    cached: bool,
+    owner: Rc<str>,
+    flags: [u8; 4],
+}
+
+#[derive(FieldNames)]
+#[field_names(only_impl = "std::fmt::Display")]
+#[allow(dead_code)]
+struct Mixed<'a> {
+    title: &'a str,
+    tags: Vec<String>,
+    count: usize,
+}
+
+#[derive(FieldNames)]
+#[field_names(only_impl = "Copy")]
+#[allow(dead_code)]
+struct Generic<T> {
+    value: T,
+    count: u8,
+}
+
+#[test]
+fn copy_fields() {
+    assert_eq!(Example::fields_implementing(), ["id", "score", "flags"]);
+}
+
+#[test]
+fn any_trait_path() {
+    assert_eq!(Mixed::fields_implementing(), ["title", "count"]);
+}
+
+/// `T: Copy` can't be proven inside the generated impl, so `value` is left out even when `T`
+/// is `Copy`.
+#[test]
+fn type_parameters_fall_back() {
+    assert_eq!(Generic::<u8>::fields_implementing(), ["count"]);
+}
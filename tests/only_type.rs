@@ -0,0 +1,16 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(only_type = "String")]
+#[allow(dead_code)]
+struct Example {
+    name: String,
+    age: u8,
+    email: std::string::String,
+    nickname: Option<String>,
+}
+
+#[test]
+fn only_strings() {
+    assert_eq!(Example::FIELDS, ["name", "email"]);
+}
@@ -0,0 +1,74 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames, Clone, Copy)]
+#[field_names(impl_trait, missing, diff)]
+#[repr(C, packed)]
+struct Packed {
+    flag: u8,
+    count: u64,
+    limit: Option<u32>,
+}
+
+#[derive(FieldNames, Clone, Copy)]
+#[field_names(missing, diff, layout_order)]
+#[repr(C, packed(2))]
+struct PackedC {
+    flag: u8,
+    count: u64,
+    limit: Option<u32>,
+}
+
+#[derive(FieldNames)]
+#[field_names(setters)]
+#[repr(C, packed)]
+struct Settable {
+    flag: u8,
+    name: String,
+}
+
+#[test]
+fn names() {
+    assert_eq!(Packed::FIELDS, ["flag", "count", "limit"]);
+    assert_eq!(<Packed as FieldNames>::FIELDS, ["flag", "count", "limit"]);
+    assert_eq!(PackedC::FIELDS, ["flag", "count", "limit"]);
+}
+
+#[test]
+fn instance_methods() {
+    let mut value = Packed {
+        flag: 1,
+        count: 2,
+        limit: None,
+    };
+    assert_eq!(value.missing_fields(), ["limit"]);
+
+    let original = value;
+    value.count = 3;
+    value.limit = Some(4);
+    assert!(value.missing_fields().is_empty());
+    assert_eq!(value.changed_fields(&original), ["count", "limit"]);
+}
+
+#[test]
+fn setters() {
+    let mut value = Settable {
+        flag: 1,
+        name: String::new(),
+    };
+    value.set_field_from_str("flag", "2").unwrap();
+    value.set_field_from_str("name", "Ada").unwrap();
+    assert_eq!({ value.flag }, 2);
+    assert_eq!({ value.name }, "Ada");
+}
+
+#[test]
+fn packed_with_alignment() {
+    let value = PackedC {
+        flag: 1,
+        count: 2,
+        limit: Some(3),
+    };
+    let other = PackedC { flag: 0, ..value };
+    assert!(value.missing_fields().is_empty());
+    assert_eq!(value.changed_fields(&other), ["flag"]);
+}
@@ -0,0 +1,16 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(prefix = "app.", suffix = "_v2", rename_all = "camelCase")]
+#[allow(dead_code)]
+struct Example {
+    display_name: String,
+    #[field_names(rename = "id")]
+    identifier: u64,
+}
+
+#[test]
+fn prefix_and_suffix() {
+    assert_eq!(Example::FIELDS, ["app.displayName_v2", "app.id_v2"]);
+    assert_eq!(Example::FIELDS_ORIGINAL, ["display_name", "identifier"]);
+}
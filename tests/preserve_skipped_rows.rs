@@ -0,0 +1,73 @@
+use std::any::TypeId;
+
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(
+    with_span_info,
+    type_ids,
+    preserve_skipped_rows,
+    skip_matching = "tmp_*"
+)]
+#[allow(dead_code)]
+struct Example {
+    hello: String,
+    #[field_names(skip)]
+    secret: bool,
+    world: u8,
+    tmp_cache: Vec<u8>,
+}
+
+#[derive(FieldNames)]
+#[field_names(type_ids)]
+#[allow(dead_code)]
+struct NotPreserved {
+    hello: String,
+    #[field_names(skip)]
+    secret: bool,
+}
+
+#[test]
+fn fields_exclude_skipped() {
+    assert_eq!(Example::FIELDS, ["hello", "world"]);
+}
+
+#[test]
+fn rows_line_up_with_declared_fields() {
+    assert_eq!(Example::FIELDS_SKIPPED, [false, true, false, true]);
+
+    let names = Example::FIELD_LOCATIONS.map(|location| location.name);
+    assert_eq!(names, ["hello", "secret", "world", "tmp_cache"]);
+    let lines = Example::FIELD_LOCATIONS.map(|location| location.line);
+    let first = lines[0];
+    assert_eq!(lines, [first, first + 2, first + 3, first + 4]);
+
+    assert_eq!(
+        Example::fields_with_type_ids(),
+        [
+            ("hello", TypeId::of::<String>()),
+            ("secret", TypeId::of::<bool>()),
+            ("world", TypeId::of::<u8>()),
+            ("tmp_cache", TypeId::of::<Vec<u8>>()),
+        ]
+    );
+}
+
+#[test]
+fn emitted_rows() {
+    let emitted = Example::FIELD_LOCATIONS
+        .iter()
+        .zip(Example::FIELDS_SKIPPED)
+        .filter(|(_, skipped)| !skipped)
+        .map(|(location, _)| location.name)
+        .collect::<Vec<_>>();
+    assert_eq!(emitted, Example::FIELDS);
+}
+
+#[test]
+fn skipped_rows_are_dropped_by_default() {
+    assert_eq!(
+        NotPreserved::fields_with_type_ids(),
+        [("hello", TypeId::of::<String>())]
+    );
+}
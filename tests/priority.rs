@@ -0,0 +1,39 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[allow(dead_code)]
+struct Listing {
+    #[field_names(priority = 2)]
+    notes: String,
+    title: String,
+    #[field_names(priority = 1)]
+    price: u32,
+    author: String,
+    #[field_names(priority = 1)]
+    stock: u32,
+}
+
+#[test]
+fn sorted_by_priority() {
+    assert_eq!(
+        Listing::FIELDS,
+        ["title", "author", "price", "stock", "notes"]
+    );
+}
+
+#[derive(FieldNames)]
+#[field_names(reverse)]
+#[allow(dead_code)]
+struct Reversed {
+    #[field_names(priority = 1)]
+    first: u8,
+    second: u8,
+    #[field_names(priority = 1)]
+    third: u8,
+    fourth: u8,
+}
+
+#[test]
+fn reverse_breaks_ties() {
+    assert_eq!(Reversed::FIELDS, ["fourth", "second", "third", "first"]);
+}
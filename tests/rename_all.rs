@@ -0,0 +1,58 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(rename_all = "camelCase")]
+#[allow(dead_code)]
+struct Example {
+    hello: String,
+    minutes_to_midnight: u32,
+    #[field_names(skip)]
+    hidden_value: bool,
+}
+
+#[test]
+fn renamed_and_original() {
+    assert_eq!(Example::FIELDS, ["hello", "minutesToMidnight"]);
+    assert_eq!(Example::FIELDS_ORIGINAL, ["hello", "minutes_to_midnight"]);
+}
+
+#[derive(FieldNames)]
+#[field_names(rename_all = "snake_case")]
+#[allow(dead_code, non_snake_case)]
+struct Acronyms {
+    HTTPStatus: u16,
+}
+
+/// The `heck` feature never changes a plain `rename_all`, so enabling it anywhere in a build
+/// can't rename another crate's fields.
+#[test]
+fn plain_rule_splits_words_as_serde() {
+    assert_eq!(Acronyms::FIELDS, ["HTTPStatus"]);
+}
+
+#[cfg(feature = "heck")]
+#[derive(FieldNames)]
+#[field_names(
+    rename_all(rule = "snake_case", words = "heck"),
+    also(kebab_case = "FIELDS_KEBAB")
+)]
+#[allow(dead_code, non_snake_case)]
+struct HeckWords {
+    HTTPStatus: u16,
+}
+
+#[cfg(feature = "heck")]
+#[derive(field_names::VariantNames)]
+#[variant_names(rename_all(rule = "snake_case", words = "heck"))]
+#[allow(dead_code, clippy::upper_case_acronyms)]
+enum HeckVariants {
+    HTTPStatus,
+}
+
+#[cfg(feature = "heck")]
+#[test]
+fn heck_words() {
+    assert_eq!(HeckWords::FIELDS, ["http_status"]);
+    assert_eq!(HeckWords::FIELDS_KEBAB, ["http-status"]);
+    assert_eq!(HeckVariants::VARIANTS, ["http_status"]);
+}
@@ -0,0 +1,60 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[allow(dead_code)]
+struct Example {
+    #[field_names(rename = "userId")]
+    user_id: u64,
+    name: String,
+    #[field_names(skip)]
+    secret: String,
+}
+
+#[derive(FieldNames)]
+#[field_names(rename_all = "camelCase")]
+#[allow(dead_code)]
+struct Cased {
+    user_id: u64,
+    name: String,
+    #[field_names(rename = "name")]
+    title: String,
+}
+
+#[test]
+fn only_changed_names() {
+    assert_eq!(Example::RENAMED_FIELDS, [("user_id", "userId")]);
+}
+
+#[test]
+fn rename_all_and_explicit_renames() {
+    assert_eq!(
+        Cased::RENAMED_FIELDS,
+        [("user_id", "userId"), ("title", "name")]
+    );
+}
+
+#[derive(FieldNames)]
+#[allow(dead_code)]
+struct Unchanged {
+    user_id: u64,
+}
+
+/// Without renamed fields there is no `RENAMED_FIELDS`, so the name stays free for the
+/// struct's own items, and `also` may take it.
+#[allow(dead_code)]
+impl Unchanged {
+    const RENAMED_FIELDS: () = ();
+}
+
+#[derive(FieldNames)]
+#[field_names(also(camelCase = "RENAMED_FIELDS"))]
+#[allow(dead_code)]
+struct AlsoTaken {
+    user_id: u64,
+}
+
+#[test]
+fn no_renamed_fields() {
+    assert_eq!(Unchanged::RENAMED_FIELDS, ());
+    assert_eq!(AlsoTaken::RENAMED_FIELDS, ["userId"]);
+}
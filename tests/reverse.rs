@@ -0,0 +1,41 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(
+    impl_trait,
+    reverse,
+    rename_all = "camelCase",
+    name_lens,
+    bytes,
+    bitset
+)]
+#[allow(dead_code)]
+struct Legacy {
+    first_field: u8,
+    #[field_names(skip)]
+    secret: u8,
+    second: u8,
+    third_field: u8,
+}
+
+#[test]
+fn reversed() {
+    let expected = ["thirdField", "second", "firstField"];
+    assert_eq!(Legacy::FIELDS, expected);
+    assert_eq!(<Legacy as FieldNames>::FIELDS, expected);
+    assert_eq!(Legacy::all_field_names(), expected);
+    assert_eq!(
+        Legacy::FIELDS_ORIGINAL,
+        ["third_field", "second", "first_field"]
+    );
+}
+
+#[test]
+fn parallel_arrays_match() {
+    assert_eq!(Legacy::FIELD_NAME_LENS, [10, 6, 10]);
+    for (name, bytes) in Legacy::FIELDS.iter().zip(Legacy::FIELDS_BYTES) {
+        assert_eq!(name.as_bytes(), bytes);
+    }
+    assert_eq!(LegacyFields::THIRD_FIELD.bits(), 1);
+    assert_eq!(LegacyFields::FIRST_FIELD.bits(), 4);
+}
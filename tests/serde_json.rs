@@ -0,0 +1,22 @@
+#![cfg(feature = "serde_json")]
+
+use field_names::FieldNames;
+use serde_json::json;
+
+#[derive(FieldNames)]
+#[field_names(rename_all = "camelCase")]
+#[allow(dead_code)]
+struct Example {
+    hello: String,
+    minutes_to_midnight: u32,
+    #[field_names(skip)]
+    hidden: bool,
+}
+
+#[test]
+fn json_array() {
+    assert_eq!(
+        Example::fields_as_json_value(),
+        json!(["hello", "minutesToMidnight"])
+    );
+}
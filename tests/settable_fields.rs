@@ -0,0 +1,20 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[allow(dead_code)]
+struct RequestBuilder {
+    #[field_names(settable)]
+    url: String,
+    #[field_names(settable)]
+    timeout_secs: u64,
+    retries_left: u8,
+}
+
+#[test]
+fn settable_subset() {
+    assert_eq!(
+        RequestBuilder::FIELDS,
+        ["url", "timeout_secs", "retries_left"]
+    );
+    assert_eq!(RequestBuilder::SETTABLE_FIELDS, ["url", "timeout_secs"]);
+}
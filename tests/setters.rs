@@ -0,0 +1,60 @@
+use field_names::{FieldNames, SetFieldError};
+
+#[derive(Debug, Default, FieldNames)]
+#[field_names(setters)]
+struct Config {
+    name: String,
+    retries: u8,
+    #[field_names(skip)]
+    cache: Vec<u8>,
+}
+
+#[test]
+fn sets_fields() {
+    let mut config = Config::default();
+    config.set_field_from_str("name", "primary").unwrap();
+    config.set_field_from_str("retries", "3").unwrap();
+    assert_eq!(config.name, "primary");
+    assert_eq!(config.retries, 3);
+    assert!(config.cache.is_empty());
+}
+
+#[test]
+fn unknown_field() {
+    let mut config = Config::default();
+    assert_eq!(
+        config.set_field_from_str("cache", "1"),
+        Err(SetFieldError::UnknownField("cache".to_string()))
+    );
+}
+
+#[test]
+fn invalid_value() {
+    let mut config = Config::default();
+    let err = config.set_field_from_str("retries", "many").unwrap_err();
+    assert_eq!(
+        err,
+        SetFieldError::InvalidValue {
+            field: "retries",
+            message: "invalid digit found in string".to_string(),
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "invalid value for field `retries`: invalid digit found in string"
+    );
+    assert_eq!(config.retries, 0);
+}
+
+#[derive(Default, FieldNames)]
+#[field_names(setters)]
+struct Wrapper<T> {
+    value: T,
+}
+
+#[test]
+fn generic_field() {
+    let mut wrapper = Wrapper::<f64>::default();
+    wrapper.set_field_from_str("value", "1.5").unwrap();
+    assert_eq!(wrapper.value, 1.5);
+}
@@ -0,0 +1,17 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(skip_doc_hidden)]
+#[allow(dead_code)]
+struct Example {
+    /// The public name.
+    name: String,
+    #[doc(hidden)]
+    generation: u64,
+    age: u8,
+}
+
+#[test]
+fn skip_doc_hidden() {
+    assert_eq!(Example::FIELDS, ["name", "age"]);
+}
@@ -0,0 +1,41 @@
+use field_names::{FieldNames, VariantNames};
+
+#[derive(FieldNames)]
+#[field_names(with_source_file)]
+#[allow(dead_code)]
+struct Example {
+    hello: String,
+}
+
+#[derive(VariantNames)]
+#[variant_names(with_source_file)]
+#[allow(dead_code)]
+enum Choice {
+    Yes,
+    No,
+}
+
+#[test]
+fn defining_file() {
+    assert!(Example::source_file().ends_with("source_file.rs"));
+    assert_eq!(Choice::source_file(), Example::source_file());
+    assert_eq!(Example::source_file(), file!());
+}
+
+#[derive(VariantNames)]
+#[allow(dead_code)]
+enum OwnSourceFile {
+    Yes,
+    No,
+}
+
+impl OwnSourceFile {
+    fn source_file() -> &'static str {
+        "own.rs"
+    }
+}
+
+#[test]
+fn opt_in() {
+    assert_eq!(OwnSourceFile::source_file(), "own.rs");
+}
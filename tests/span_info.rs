@@ -0,0 +1,26 @@
+use field_names::{FieldLocation, FieldNames};
+
+const BEFORE_STRUCT: u32 = line!();
+#[derive(FieldNames)]
+#[field_names(with_span_info, rename_all = "camelCase")]
+#[allow(dead_code)]
+#[rustfmt::skip]
+struct Example {
+    first_name: String,
+    #[field_names(skip)]
+    hidden: bool,
+      indented: u8,
+}
+
+#[test]
+fn locations() {
+    let [first, indented]: [FieldLocation; 2] = Example::FIELD_LOCATIONS;
+    assert_eq!(first.name, "firstName");
+    assert!(first.file.ends_with("span_info.rs"));
+    assert_eq!(first.line, BEFORE_STRUCT + 6);
+    assert_eq!(first.column, 5);
+
+    assert_eq!(indented.name, "indented");
+    assert_eq!(indented.line, BEFORE_STRUCT + 9);
+    assert_eq!(indented.column, 7);
+}
@@ -0,0 +1,52 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[allow(dead_code)]
+struct Raw {
+    target: String,
+    lorem: String,
+    ipsum: String,
+}
+
+// Generates the `base_in_sync_with_raw` test.
+#[derive(FieldNames)]
+#[field_names(sync_with = "Raw", omit = "lorem_computation")]
+#[allow(dead_code)]
+struct Base {
+    target: String,
+    lorem: String,
+    lorem_computation: String,
+    ipsum: String,
+    #[field_names(skip)]
+    secret: bool,
+}
+
+mod nested {
+    // Generates the `renamed_in_sync_with_raw` test.
+    #[derive(field_names::FieldNames)]
+    #[field_names(sync_with = "super::Raw")]
+    #[allow(dead_code)]
+    struct Renamed {
+        #[field_names(rename = "target")]
+        dest: String,
+        lorem: String,
+        ipsum: String,
+    }
+}
+
+// The mismatched struct is declared inside a function, so the test harness doesn't collect its
+// generated test, and this test calls it instead.
+#[test]
+#[should_panic(expected = "`Raw` does not have the expected fields")]
+fn mismatch_fails() {
+    #[derive(FieldNames)]
+    #[field_names(sync_with = "Raw")]
+    #[allow(dead_code)]
+    struct Stale {
+        dest: String,
+        lorem: String,
+        ipsum: String,
+    }
+
+    __stale_sync_with::stale_in_sync_with_raw();
+}
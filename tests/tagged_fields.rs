@@ -0,0 +1,24 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(rename_all = "camelCase")]
+#[allow(dead_code)]
+struct Example {
+    user_id: u64,
+    #[field_names(tag)]
+    display_name: String,
+    created_at: u64,
+    #[field_names(tag, rename = "mail")]
+    email: String,
+    #[field_names(tag, skip)]
+    secret: String,
+}
+
+#[test]
+fn tagged_fields() {
+    assert_eq!(
+        Example::FIELDS,
+        ["userId", "displayName", "createdAt", "mail"]
+    );
+    assert_eq!(Example::TAGGED_FIELDS, ["displayName", "mail"]);
+}
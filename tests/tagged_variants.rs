@@ -0,0 +1,26 @@
+use field_names::VariantNames;
+
+#[derive(VariantNames)]
+#[variant_names(rename_all = "kebab-case")]
+#[allow(dead_code)]
+enum Event {
+    #[variant_names(tag)]
+    SignedUp,
+    CacheEvicted,
+    #[variant_names(tag)]
+    PasswordReset {
+        at: u64,
+    },
+    Heartbeat,
+    #[variant_names(tag, skip)]
+    Hidden,
+}
+
+#[test]
+fn tagged_variants() {
+    assert_eq!(
+        Event::VARIANTS,
+        ["signed-up", "cache-evicted", "password-reset", "heartbeat"]
+    );
+    assert_eq!(Event::TAGGED_VARIANTS, ["signed-up", "password-reset"]);
+}
@@ -0,0 +1,35 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(titleize, prefix = "x_")]
+#[allow(dead_code)]
+struct Example {
+    hello: String,
+    minutes_to_midnight: u32,
+    #[field_names(rename = "httpStatusCode")]
+    status: u16,
+    #[field_names(skip)]
+    secret: bool,
+}
+
+#[derive(FieldNames)]
+#[field_names(titleize, rename_all = "camelCase")]
+#[allow(dead_code)]
+struct Renamed {
+    created_at: u64,
+    user_id: u64,
+}
+
+#[test]
+fn labels() {
+    assert_eq!(
+        Example::FIELD_LABELS,
+        ["Hello", "Minutes To Midnight", "Http Status Code"]
+    );
+}
+
+#[test]
+fn labels_follow_rename_all() {
+    assert_eq!(Renamed::FIELDS, ["createdAt", "userId"]);
+    assert_eq!(Renamed::FIELD_LABELS, ["Created At", "User Id"]);
+}
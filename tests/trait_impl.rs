@@ -0,0 +1,68 @@
+//! `#[field_names(impl_trait)]` implements the `FieldNames` trait alongside the inherent const.
+
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(impl_trait)]
+#[allow(dead_code)]
+struct Example {
+    hello: String,
+    world: String,
+    #[field_names(skip)]
+    hidden: bool,
+}
+
+/// Deliberately does not implement `FieldNames`; if the derive added a `T: FieldNames` bound
+/// to the impl for `Wrapper<T>`, the tests using `Wrapper<Opaque>` would not compile.
+struct Opaque;
+
+#[derive(FieldNames)]
+#[field_names(impl_trait)]
+#[allow(dead_code)]
+struct Wrapper<T> {
+    value: T,
+}
+
+#[derive(FieldNames)]
+#[field_names(impl_trait)]
+#[allow(dead_code)]
+struct Bounded<T: Clone>
+where
+    T: Default,
+{
+    value: T,
+}
+
+#[derive(FieldNames)]
+#[field_names(impl_trait)]
+#[allow(dead_code)]
+struct Unsized<T: ?Sized> {
+    value: Box<T>,
+}
+
+fn trait_fields<T: FieldNames>() -> &'static [&'static str] {
+    T::FIELDS
+}
+
+#[test]
+fn trait_matches_inherent() {
+    assert_eq!(trait_fields::<Example>(), &Example::FIELDS[..]);
+    assert_eq!(<Example as FieldNames>::FIELDS, &["hello", "world"]);
+}
+
+#[test]
+fn generic_without_trait_bound() {
+    let _ = Opaque;
+    assert_eq!(trait_fields::<Wrapper<Opaque>>(), &["value"]);
+}
+
+#[test]
+fn keeps_declared_bounds() {
+    assert_eq!(trait_fields::<Bounded<String>>(), &["value"]);
+}
+
+#[test]
+fn unsized_param() {
+    // Any inferred `T: Sized` or `T: FieldNames` bound would reject `dyn Debug`.
+    assert_eq!(trait_fields::<Unsized<dyn std::fmt::Debug>>(), &["value"]);
+}
@@ -0,0 +1,16 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(type_and_fields)]
+#[allow(dead_code)]
+struct Example<T> {
+    hello: T,
+    world: String,
+}
+
+#[test]
+fn includes_concrete_type() {
+    let (type_name, fields) = Example::<u8>::type_and_fields();
+    assert!(type_name.ends_with("Example<u8>"), "{}", type_name);
+    assert_eq!(fields, &["hello", "world"]);
+}
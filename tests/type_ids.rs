@@ -0,0 +1,45 @@
+use std::any::TypeId;
+
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(type_ids, rename_all = "camelCase")]
+#[allow(dead_code)]
+struct Example {
+    user_id: u64,
+    #[field_names(skip)]
+    secret: String,
+    display_name: String,
+    tags: Vec<&'static str>,
+}
+
+#[derive(FieldNames)]
+#[field_names(type_ids)]
+#[allow(dead_code)]
+struct Generic<T> {
+    value: T,
+    count: usize,
+}
+
+#[test]
+fn type_ids() {
+    assert_eq!(
+        Example::fields_with_type_ids(),
+        [
+            ("userId", TypeId::of::<u64>()),
+            ("displayName", TypeId::of::<String>()),
+            ("tags", TypeId::of::<Vec<&'static str>>()),
+        ]
+    );
+}
+
+#[test]
+fn generic() {
+    assert_eq!(
+        Generic::<bool>::fields_with_type_ids(),
+        [
+            ("value", TypeId::of::<bool>()),
+            ("count", TypeId::of::<usize>()),
+        ]
+    );
+}
@@ -0,0 +1,60 @@
+use field_names::{FieldNames, Names, VariantNames};
+
+#[derive(FieldNames)]
+#[field_names(rename_all = "camelCase", with_type_name)]
+#[allow(dead_code)]
+struct Example {
+    user_id: u64,
+}
+
+#[derive(VariantNames)]
+#[variant_names(with_type_name)]
+#[allow(dead_code)]
+enum Choice {
+    Yes,
+    No,
+}
+
+mod nested {
+    #[derive(field_names::FieldNames)]
+    #[field_names(with_type_name)]
+    #[allow(dead_code, non_camel_case_types)]
+    pub struct r#Raw<T> {
+        value: T,
+    }
+
+    #[test]
+    fn declared_ident_only() {
+        assert_eq!(Raw::<u8>::TYPE_NAME, "Raw");
+    }
+}
+
+#[derive(Names)]
+#[field_names(with_type_name)]
+#[allow(dead_code)]
+struct Both {
+    hello: String,
+}
+
+#[test]
+fn type_name() {
+    assert_eq!(Example::TYPE_NAME, "Example");
+    assert_eq!(Choice::TYPE_NAME, "Choice");
+    assert_eq!(Both::TYPE_NAME, "Both");
+}
+
+/// Without `with_type_name`, the type is free to define `TYPE_NAME` itself.
+#[derive(FieldNames)]
+#[allow(dead_code)]
+struct OwnTypeName {
+    hello: String,
+}
+
+impl OwnTypeName {
+    const TYPE_NAME: &'static str = "own";
+}
+
+#[test]
+fn opt_in() {
+    assert_eq!(OwnTypeName::TYPE_NAME, "own");
+}
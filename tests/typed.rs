@@ -0,0 +1,30 @@
+use field_names::{FieldName, FieldNames};
+
+#[derive(FieldNames)]
+#[field_names(typed)]
+#[allow(dead_code)]
+struct Example {
+    hello: String,
+    minutes_to_midnight: u32,
+}
+
+#[test]
+fn compares_with_str() {
+    assert_eq!(Example::FIELDS[0], "hello");
+    assert_eq!("minutes_to_midnight", Example::FIELDS[1]);
+    assert_ne!(Example::FIELDS[0], "world");
+    assert_eq!(Example::FIELDS[1], FieldName("minutes_to_midnight"));
+}
+
+#[test]
+fn derefs_to_str() {
+    assert_eq!(Example::FIELDS[0].len(), 5);
+    assert!(Example::FIELDS[1].starts_with("minutes"));
+    let name: &str = &Example::FIELDS[0];
+    assert_eq!(name, "hello");
+}
+
+#[test]
+fn displays_name() {
+    assert_eq!(Example::FIELDS[1].to_string(), "minutes_to_midnight");
+}
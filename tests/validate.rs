@@ -0,0 +1,31 @@
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(impl_trait)]
+#[allow(dead_code)]
+struct Base {
+    id: u64,
+}
+
+#[derive(FieldNames)]
+#[field_names(validate)]
+#[allow(dead_code)]
+struct Example {
+    hello: String,
+    #[field_names(skip)]
+    world: String,
+}
+
+#[derive(FieldNames)]
+#[field_names(validate, extends = "Base")]
+#[allow(dead_code)]
+struct Extended {
+    #[field_names(skip)]
+    hidden: bool,
+}
+
+#[test]
+fn validate() {
+    assert_eq!(Example::FIELDS, ["hello"]);
+    assert_eq!(Extended::FIELDS, ["id"]);
+}
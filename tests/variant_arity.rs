@@ -0,0 +1,25 @@
+use field_names::VariantNames;
+
+#[derive(VariantNames)]
+#[variant_names(with_arity)]
+#[allow(dead_code)]
+enum Example {
+    Unit,
+    Tuple(String, u8),
+    World {
+        planet: String,
+        #[variant_names(skip)]
+        secret: bool,
+        person: String,
+    },
+    #[variant_names(skip)]
+    Hidden,
+}
+
+#[test]
+fn variant_arity() {
+    assert_eq!(
+        Example::VARIANTS_ARITY,
+        [("Unit", 0), ("Tuple", 2), ("World", 3)]
+    );
+}
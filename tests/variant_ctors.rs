@@ -0,0 +1,27 @@
+use field_names::VariantNames;
+
+#[derive(Debug, PartialEq, VariantNames)]
+#[variant_names(with_ctors, rename_all = "snake_case")]
+enum Level {
+    Debug,
+    Info,
+    HighPriority,
+    #[variant_names(skip)]
+    #[allow(dead_code)]
+    Custom(u8),
+}
+
+fn construct(name: &str) -> Option<Level> {
+    Level::VARIANT_CTORS
+        .iter()
+        .find(|(variant, _)| *variant == name)
+        .map(|(_, ctor)| ctor())
+}
+
+#[test]
+fn construct_by_name() {
+    assert_eq!(construct("debug"), Some(Level::Debug));
+    assert_eq!(construct("info"), Some(Level::Info));
+    assert_eq!(construct("high_priority"), Some(Level::HighPriority));
+    assert_eq!(construct("custom"), None);
+}
@@ -0,0 +1,53 @@
+use field_names::VariantNames;
+
+#[derive(VariantNames)]
+#[variant_names(with_fields)]
+#[allow(dead_code)]
+enum Example {
+    Unit,
+    Tuple(String, u8),
+    World {
+        planet: String,
+        #[variant_names(skip)]
+        secret: bool,
+        person: String,
+    },
+    #[variant_names(skip)]
+    Hidden,
+}
+
+#[test]
+fn variant_fields() {
+    assert_eq!(
+        Example::VARIANT_FIELDS,
+        [
+            ("Unit", &[][..]),
+            ("Tuple", &["0", "1"][..]),
+            ("World", &["planet", "person"][..]),
+        ]
+    );
+}
+
+#[derive(VariantNames)]
+#[variant_names(with_fields, with_arity, field_consts)]
+#[allow(dead_code)]
+enum Request {
+    Send {
+        to: String,
+        #[variant_names(skip)]
+        retry_count: u8,
+        body: String,
+    },
+    Tuple(u8, #[variant_names(skip)] u8, u8),
+}
+
+#[test]
+fn skipped_inner_fields() {
+    assert_eq!(
+        Request::VARIANT_FIELDS,
+        [("Send", &["to", "body"][..]), ("Tuple", &["0", "2"][..]),]
+    );
+    assert_eq!(Request::SEND_FIELDS, ["to", "body"]);
+    assert_eq!(Request::TUPLE_FIELDS, ["0", "2"]);
+    assert_eq!(Request::VARIANTS_ARITY, [("Send", 3), ("Tuple", 3)]);
+}
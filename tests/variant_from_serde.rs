@@ -0,0 +1,105 @@
+use field_names::VariantNames;
+use serde::Serialize;
+
+#[derive(VariantNames, Serialize)]
+#[variant_names(with_fields, from_serde)]
+#[serde(rename_all = "snake_case", rename_all_fields = "camelCase")]
+#[allow(dead_code)]
+enum Event {
+    Started,
+    Progress(u8),
+    SendRequest {
+        request_id: u64,
+        #[serde(skip_serializing)]
+        retry_count: u8,
+        #[serde(rename = "who")]
+        sent_by: String,
+    },
+    #[serde(skip)]
+    Internal,
+}
+
+fn wire_fields<T: Serialize>(value: &T) -> (String, Vec<String>) {
+    match serde_json::to_value(value).unwrap() {
+        serde_json::Value::Object(map) => {
+            let (name, inner) = map.into_iter().next().unwrap();
+            match inner {
+                serde_json::Value::Object(fields) => (name, fields.keys().cloned().collect()),
+                _ => unreachable!(),
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn variant_fields() {
+    assert_eq!(Event::VARIANTS, ["started", "progress", "send_request"]);
+    assert_eq!(
+        Event::VARIANT_FIELDS,
+        [
+            ("started", &[][..]),
+            ("progress", &["0"][..]),
+            ("send_request", &["requestId", "who"][..]),
+        ]
+    );
+}
+
+#[test]
+fn matches_serde() {
+    let value = Event::SendRequest {
+        request_id: 1,
+        retry_count: 0,
+        sent_by: "Ada".into(),
+    };
+    let mut expected = Event::VARIANT_FIELDS[2]
+        .1
+        .iter()
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>();
+    expected.sort();
+    assert_eq!(
+        wire_fields(&value),
+        (Event::VARIANT_FIELDS[2].0.to_string(), expected)
+    );
+}
+
+/// A rule read from `#[serde(rename_all_fields)]` never uses `heck`'s word splitting, so the
+/// names stay serde's even with the feature enabled.
+#[cfg(feature = "heck")]
+#[derive(VariantNames, Serialize)]
+#[variant_names(with_fields, from_serde)]
+#[serde(rename_all_fields = "snake_case")]
+#[allow(dead_code, non_snake_case)]
+enum Response {
+    Sent { HTTPStatus: u16 },
+}
+
+#[cfg(feature = "heck")]
+#[test]
+fn serde_field_rule_ignores_heck() {
+    let value = Response::Sent { HTTPStatus: 200 };
+    assert_eq!(Response::VARIANT_FIELDS, [("Sent", &["HTTPStatus"][..])]);
+    assert_eq!(
+        wire_fields(&value),
+        ("Sent".to_string(), vec!["HTTPStatus".to_string()])
+    );
+}
+
+#[derive(VariantNames, Serialize)]
+#[variant_names(from_serde)]
+#[serde(rename_all = "snake_case")]
+#[allow(dead_code, clippy::upper_case_acronyms)]
+enum Status {
+    HTTPStatus,
+}
+
+/// Holds with or without `heck`, which only applies to a `rename_all` with `words = "heck"`.
+#[test]
+fn serde_rename_all_matches_serde_on_acronyms() {
+    assert_eq!(Status::VARIANTS, ["h_t_t_p_status"]);
+    assert_eq!(
+        serde_json::to_value(Status::HTTPStatus).unwrap(),
+        Status::VARIANTS[0]
+    );
+}
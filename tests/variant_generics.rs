@@ -0,0 +1,60 @@
+use field_names::VariantNames;
+
+#[derive(VariantNames)]
+#[variant_names(with_fields)]
+#[allow(dead_code)]
+enum Either<L, R = ()> {
+    Left(L),
+    Right(R),
+}
+
+#[derive(VariantNames)]
+#[allow(dead_code)]
+enum Tagged<'a, T: Clone = u8, const N: usize = 4>
+where
+    T: 'a,
+{
+    Borrowed(&'a T),
+    Owned([T; N]),
+}
+
+/// Defaults only apply when the enum is named as a type, hence `<Either<u8>>::VARIANTS` rather
+/// than `Either::<u8>::VARIANTS`, which would leave `R` to inference.
+#[test]
+fn defaulted_params() {
+    assert_eq!(<Either<u8>>::VARIANTS, ["Left", "Right"]);
+    assert_eq!(<Either<u8, String>>::VARIANTS, ["Left", "Right"]);
+    assert_eq!(
+        <Either<u8>>::VARIANT_FIELDS,
+        [("Left", &["0"][..]), ("Right", &["0"][..])]
+    );
+    assert_eq!(<Tagged<'static>>::VARIANTS, ["Borrowed", "Owned"]);
+    assert_eq!(
+        <Tagged<'static, String, 2>>::VARIANTS,
+        ["Borrowed", "Owned"]
+    );
+}
+
+trait Listed {
+    const VARIANTS: &'static [&'static str];
+}
+
+#[derive(VariantNames)]
+#[variant_names(kind_enum, with_ctors, schema, impl_trait = "Listed")]
+#[allow(dead_code)]
+enum Slot<'a, T: 'a, const N: usize> {
+    Empty,
+    #[variant_names(skip)]
+    Full(&'a [T; N]),
+}
+
+#[test]
+fn generated_items_with_params() {
+    assert_eq!(<Slot<'static, u8, 1>>::VARIANT_KINDS, [SlotKind::Empty]);
+    assert!(matches!(
+        (<Slot<'static, u8, 1>>::VARIANT_CTORS[0].1)(),
+        Slot::Empty
+    ));
+    assert_eq!(<Slot<'static, u8, 1>>::SCHEMA[0].name, "Empty");
+    assert_eq!(<Slot<'static, String, 3> as Listed>::VARIANTS, ["Empty"]);
+}
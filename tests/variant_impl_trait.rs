@@ -0,0 +1,62 @@
+use field_names::VariantNames;
+
+trait Enumerable {
+    const VALUES: &'static [&'static str];
+}
+
+trait Listed {
+    const VARIANTS: &'static [&'static str];
+}
+
+fn values<T: Enumerable>() -> &'static [&'static str] {
+    T::VALUES
+}
+
+#[derive(VariantNames)]
+#[variant_names(
+    impl_trait = "Enumerable",
+    const_name = "VALUES",
+    rename_all = "lowercase"
+)]
+#[allow(dead_code)]
+enum Level {
+    Debug,
+    Info,
+    #[variant_names(skip)]
+    Trace,
+}
+
+#[derive(VariantNames)]
+#[variant_names(impl_trait = "Listed")]
+#[allow(dead_code)]
+enum Toggle<T> {
+    On(T),
+    Off,
+}
+
+#[test]
+fn custom_const_name() {
+    assert_eq!(values::<Level>(), ["debug", "info"]);
+}
+
+#[test]
+fn default_const_name() {
+    assert_eq!(<Toggle<String> as Listed>::VARIANTS, ["On", "Off"]);
+}
+
+mod reexported {
+    pub use field_names::*;
+}
+
+#[derive(VariantNames)]
+#[variant_names(crate = "crate::reexported", schema)]
+#[allow(dead_code)]
+enum Shape {
+    Circle { radius: f64 },
+}
+
+#[test]
+fn crate_path() {
+    assert_eq!(Shape::SCHEMA[0].style, reexported::VariantStyle::Struct);
+    assert_eq!(Shape::SCHEMA[0].fields, ["radius"]);
+}
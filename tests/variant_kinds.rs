@@ -0,0 +1,49 @@
+use field_names::VariantNames;
+
+#[derive(VariantNames)]
+#[variant_names(kind_enum, rename_all = "kebab-case")]
+#[allow(dead_code)]
+enum Event {
+    Started,
+    SendRequest {
+        id: u64,
+    },
+    #[variant_names(skip)]
+    Internal(u8),
+    Finished(String),
+}
+
+#[derive(VariantNames)]
+#[variant_names(kind_enum = "ShapeTag")]
+#[allow(dead_code)]
+enum Shape<T> {
+    Point,
+    Circle(T),
+}
+
+#[test]
+fn iterate_kinds() {
+    let names = Event::VARIANT_KINDS
+        .iter()
+        .map(|kind| kind.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, Event::VARIANTS);
+    assert_eq!(
+        Event::VARIANT_KINDS,
+        [
+            EventKind::Started,
+            EventKind::SendRequest,
+            EventKind::Finished
+        ]
+    );
+    assert_eq!(EventKind::SendRequest.to_string(), "send-request");
+}
+
+#[test]
+fn named_kind_enum() {
+    assert_eq!(
+        Shape::<f32>::VARIANT_KINDS,
+        [ShapeTag::Point, ShapeTag::Circle]
+    );
+    assert_eq!(ShapeTag::Circle.as_str(), "Circle");
+}
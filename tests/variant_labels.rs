@@ -0,0 +1,37 @@
+use field_names::VariantNames;
+
+#[derive(VariantNames)]
+#[variant_names(titleize)]
+#[allow(dead_code)]
+enum Action {
+    Open,
+    SendRequest,
+    #[variant_names(skip)]
+    Internal,
+    HTTPRedirect(String),
+    SaveAsPdf {
+        path: String,
+    },
+}
+
+#[derive(VariantNames)]
+#[variant_names(titleize, rename_all = "kebab-case")]
+#[allow(dead_code)]
+enum Renamed {
+    Quit,
+    GoBack,
+}
+
+#[test]
+fn labels() {
+    assert_eq!(
+        Action::VARIANT_LABELS,
+        ["Open", "Send Request", "HTTP Redirect", "Save As Pdf"]
+    );
+}
+
+#[test]
+fn single_word_and_renamed() {
+    assert_eq!(Renamed::VARIANTS, ["quit", "go-back"]);
+    assert_eq!(Renamed::VARIANT_LABELS, ["Quit", "Go Back"]);
+}
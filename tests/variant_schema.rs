@@ -0,0 +1,65 @@
+use field_names::{FieldNames, VariantNames, VariantSchema, VariantStyle};
+
+#[derive(FieldNames)]
+#[field_names(impl_trait)]
+#[allow(dead_code)]
+struct Payload {
+    id: u64,
+    body: String,
+}
+
+#[derive(VariantNames)]
+#[variant_names(schema, rename_all = "snake_case")]
+#[allow(dead_code)]
+enum Message {
+    Ping,
+    Text(String, #[variant_names(skip)] u8),
+    Move {
+        x: i32,
+        #[variant_names(rename = "vertical")]
+        y: i32,
+    },
+    #[variant_names(transparent)]
+    Upload(Payload),
+    #[variant_names(skip)]
+    Internal,
+}
+
+#[test]
+fn schema_shape() {
+    assert_eq!(
+        Message::SCHEMA,
+        [
+            VariantSchema {
+                name: "ping",
+                style: VariantStyle::Unit,
+                arity: 0,
+                fields: &[],
+            },
+            VariantSchema {
+                name: "text",
+                style: VariantStyle::Tuple,
+                arity: 2,
+                fields: &["0"],
+            },
+            VariantSchema {
+                name: "move",
+                style: VariantStyle::Struct,
+                arity: 2,
+                fields: &["x", "vertical"],
+            },
+            VariantSchema {
+                name: "upload",
+                style: VariantStyle::Tuple,
+                arity: 1,
+                fields: &["id", "body"],
+            },
+        ]
+    );
+}
+
+#[test]
+fn matches_variants() {
+    let names = Message::SCHEMA.map(|variant| variant.name);
+    assert_eq!(names, Message::VARIANTS);
+}
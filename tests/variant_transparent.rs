@@ -0,0 +1,26 @@
+use field_names::{FieldNames, VariantNames};
+
+#[derive(FieldNames)]
+#[field_names(impl_trait)]
+#[allow(dead_code)]
+struct Created {
+    id: u64,
+    author: String,
+}
+
+#[derive(VariantNames)]
+#[variant_names(with_fields)]
+#[allow(dead_code)]
+enum Event {
+    #[variant_names(transparent)]
+    Created(Created),
+    Deleted(u64),
+}
+
+#[test]
+fn transparent() {
+    assert_eq!(
+        Event::VARIANT_FIELDS,
+        [("Created", &["id", "author"][..]), ("Deleted", &["0"][..])]
+    );
+}
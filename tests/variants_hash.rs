@@ -0,0 +1,39 @@
+use field_names::VariantNames;
+
+#[derive(VariantNames)]
+#[variant_names(hash)]
+#[allow(dead_code)]
+enum Before {
+    Created,
+    Deleted,
+}
+
+#[derive(VariantNames)]
+#[variant_names(hash)]
+#[allow(dead_code)]
+enum After {
+    Created,
+    Removed,
+}
+
+#[derive(VariantNames)]
+#[variant_names(hash)]
+#[allow(dead_code)]
+enum Same {
+    Created(u8),
+    Deleted {
+        at: u64,
+    },
+    #[variant_names(skip)]
+    Unknown,
+}
+
+#[test]
+fn renaming_a_variant_changes_the_hash() {
+    assert_ne!(Before::VARIANTS_HASH, After::VARIANTS_HASH);
+}
+
+#[test]
+fn same_names_same_hash() {
+    assert_eq!(Before::VARIANTS_HASH, Same::VARIANTS_HASH);
+}
@@ -0,0 +1,69 @@
+use field_names::VariantNames;
+
+#[derive(VariantNames)]
+#[variant_names(tagged)]
+#[allow(dead_code)]
+enum Sequential {
+    Hello,
+    #[variant_names(skip)]
+    Secret(u8),
+    World {
+        planet: String,
+    },
+    Again,
+}
+
+#[derive(VariantNames)]
+#[variant_names(tagged(count_skipped))]
+#[allow(dead_code)]
+enum CountSkipped {
+    Hello,
+    #[variant_names(skip)]
+    Secret,
+    World,
+}
+
+const BASE: isize = 10;
+
+#[derive(VariantNames)]
+#[variant_names(tagged)]
+#[allow(dead_code)]
+enum Discriminants {
+    Zero,
+    One,
+    Seven = 7,
+    #[variant_names(skip)]
+    Eight,
+    Nine,
+    Ten = BASE,
+    Eleven,
+}
+
+#[test]
+fn sequential_by_default() {
+    assert_eq!(
+        Sequential::VARIANTS_TAGGED,
+        [(0, "Hello"), (1, "World"), (2, "Again")]
+    );
+}
+
+#[test]
+fn count_skipped() {
+    assert_eq!(CountSkipped::VARIANTS_TAGGED, [(0, "Hello"), (2, "World")]);
+}
+
+#[test]
+fn discriminants() {
+    assert_eq!(
+        Discriminants::VARIANTS_TAGGED,
+        [
+            (0, "Zero"),
+            (1, "One"),
+            (7, "Seven"),
+            (9, "Nine"),
+            (10, "Ten"),
+            (11, "Eleven"),
+        ]
+    );
+    assert_eq!(Discriminants::Nine as usize, 9);
+}
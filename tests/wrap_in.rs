@@ -0,0 +1,42 @@
+use field_names::FieldNames;
+
+pub struct MyCols(pub [&'static str; 2]);
+
+pub struct Labels(pub [field_names::FieldName; 2]);
+
+#[derive(FieldNames)]
+#[field_names(wrap_in = "MyCols", impl_trait)]
+#[allow(dead_code)]
+struct Example {
+    hello: String,
+    #[field_names(skip)]
+    hidden: bool,
+    world: u8,
+}
+
+#[derive(FieldNames)]
+#[field_names(wrap_in = "Labels", typed)]
+#[allow(dead_code)]
+struct Typed {
+    hello: String,
+    world: u8,
+}
+
+const COLUMNS: MyCols = Example::FIELDS;
+
+#[test]
+fn wrapped_names() {
+    assert_eq!(Example::FIELDS.0, ["hello", "world"]);
+    assert_eq!(COLUMNS.0.len(), 2);
+}
+
+#[test]
+fn trait_and_helpers_unwrapped() {
+    assert_eq!(<Example as FieldNames>::FIELDS, ["hello", "world"]);
+    assert_eq!(Example::all_field_names(), ["hello", "world"]);
+}
+
+#[test]
+fn wraps_typed_array() {
+    assert_eq!(Typed::FIELDS.0, ["hello", "world"]);
+}
@@ -0,0 +1,33 @@
+use std::borrow::Cow;
+
+use field_names::FieldNames;
+
+#[derive(FieldNames)]
+#[field_names(wrapper = "cow")]
+#[allow(dead_code)]
+struct Example {
+    hello: String,
+    world: String,
+}
+
+#[derive(FieldNames)]
+#[field_names(wrapper = "Cow", impl_trait)]
+#[allow(dead_code)]
+struct Base {
+    id: u64,
+}
+
+#[derive(FieldNames)]
+#[field_names(wrapper = "cow", extends = "Base")]
+#[allow(dead_code)]
+struct Extended {
+    name: String,
+}
+
+#[test]
+fn cow() {
+    let fields: [Cow<'static, str>; 2] = Example::fields();
+    assert_eq!(fields, [Cow::Borrowed("hello"), Cow::Borrowed("world")]);
+    assert!(fields.iter().all(|name| matches!(name, Cow::Borrowed(_))));
+    assert_eq!(Extended::fields(), ["id", "name"]);
+}